@@ -1,7 +1,165 @@
-use biome_js_syntax::{AnyJsModuleItem, JsImport, JsModule};
+use std::collections::HashMap;
+
+use biome_js_syntax::{
+    AnyJsCallArgument, AnyJsExportClause, AnyJsExpression, AnyJsImportClause,
+    AnyJsLiteralExpression, AnyJsModuleItem, AnyJsModuleSource, AnyJsStatement,
+    AnyTsModuleReference, JsExport, JsImport, JsModule, JsModuleItemList, JsVariableStatement,
+    TsImportEqualsDeclaration,
+};
+use biome_rowan::{
+    declare_node_union, AstNode, AstSeparatedList, SyntaxError, SyntaxResult, TokenText,
+};
 use itertools::Itertools;
 
-use crate::import_kind::ImportKind;
+use crate::config::Config;
+use crate::resolver::Resolver;
+use crate::sort_order::{SortKey, SortOrder};
+use crate::transform::{merge_named_imports, Remake};
+
+/// Statements that refer to another module by its specifier, i.e. imports and re-exports.
+pub trait ImportLike: AstNode + Clone {
+    fn source_text(&self) -> SyntaxResult<TokenText>;
+
+    /// Returns whether the statement has the `type` modifier, e.g. `import type { A } from 'a'`.
+    fn is_type_only(&self) -> bool;
+}
+
+impl ImportLike for JsImport {
+    fn source_text(&self) -> SyntaxResult<TokenText> {
+        JsImport::source_text(self)
+    }
+
+    fn is_type_only(&self) -> bool {
+        self.import_clause()
+            .is_ok_and(|clause| clause.type_token().is_some())
+    }
+}
+
+impl ImportLike for TsImportEqualsDeclaration {
+    fn source_text(&self) -> SyntaxResult<TokenText> {
+        let AnyTsModuleReference::TsExternalModuleReference(reference) = self.module_reference()?
+        else {
+            return Err(SyntaxError::MissingRequiredChild);
+        };
+
+        match reference.source()? {
+            AnyJsModuleSource::JsModuleSource(source) => source.inner_string_text(),
+            AnyJsModuleSource::JsMetavariable(_) => Err(SyntaxError::MissingRequiredChild),
+        }
+    }
+
+    fn is_type_only(&self) -> bool {
+        self.type_token().is_some()
+    }
+}
+
+declare_node_union! {
+    /// Imports of another module, i.e. ES imports and TypeScript's `import x = require('x')`.
+    pub AnyImport = JsImport | TsImportEqualsDeclaration
+}
+
+impl ImportLike for AnyImport {
+    fn source_text(&self) -> SyntaxResult<TokenText> {
+        match self {
+            Self::JsImport(import) => import.source_text(),
+            Self::TsImportEqualsDeclaration(import) => import.source_text(),
+        }
+    }
+
+    fn is_type_only(&self) -> bool {
+        match self {
+            Self::JsImport(import) => import.is_type_only(),
+            Self::TsImportEqualsDeclaration(import) => import.is_type_only(),
+        }
+    }
+}
+
+impl ImportLike for JsExport {
+    fn source_text(&self) -> SyntaxResult<TokenText> {
+        let source = match self.export_clause()? {
+            AnyJsExportClause::JsExportFromClause(clause) => clause.source()?,
+            AnyJsExportClause::JsExportNamedFromClause(clause) => clause.source()?,
+            _ => return Err(SyntaxError::MissingRequiredChild),
+        };
+
+        match source {
+            AnyJsModuleSource::JsModuleSource(source) => source.inner_string_text(),
+            AnyJsModuleSource::JsMetavariable(_) => Err(SyntaxError::MissingRequiredChild),
+        }
+    }
+
+    fn is_type_only(&self) -> bool {
+        match self.export_clause() {
+            Ok(AnyJsExportClause::JsExportFromClause(clause)) => clause.type_token().is_some(),
+            Ok(AnyJsExportClause::JsExportNamedFromClause(clause)) => clause.type_token().is_some(),
+            _ => false,
+        }
+    }
+}
+
+impl ImportLike for JsVariableStatement {
+    fn source_text(&self) -> SyntaxResult<TokenText> {
+        require_source(self).ok_or(SyntaxError::MissingRequiredChild)
+    }
+
+    fn is_type_only(&self) -> bool {
+        false
+    }
+}
+
+/// Returns the module specifier of the `require` call, e.g. `x` for `require('x')` and
+/// `require('x').y`.
+fn require_call_source(expression: AnyJsExpression) -> Option<TokenText> {
+    let call = match expression {
+        AnyJsExpression::JsCallExpression(call) => call,
+        AnyJsExpression::JsStaticMemberExpression(member) => {
+            return require_call_source(member.object().ok()?)
+        }
+        _ => return None,
+    };
+
+    let AnyJsExpression::JsIdentifierExpression(callee) = call.callee().ok()? else {
+        return None;
+    };
+
+    if callee.name().ok()?.value_token().ok()?.text_trimmed() != "require" {
+        return None;
+    }
+
+    let args = call.arguments().ok()?.args();
+    let Some(Ok(AnyJsCallArgument::AnyJsExpression(AnyJsExpression::AnyJsLiteralExpression(
+        AnyJsLiteralExpression::JsStringLiteralExpression(source),
+    )))) = args.first()
+    else {
+        return None;
+    };
+
+    if args.len() != 1 {
+        return None;
+    }
+
+    source.inner_string_text().ok()
+}
+
+/// Returns the module specifier of the declaration of a single variable initialized by `require`,
+/// e.g. `const x = require('x')`.
+pub fn require_source(statement: &JsVariableStatement) -> Option<TokenText> {
+    let declarators = statement.declaration().ok()?.declarators();
+    if declarators.len() != 1 {
+        return None;
+    }
+
+    let declarator = declarators.first()?.ok()?;
+
+    require_call_source(declarator.initializer()?.expression().ok()?)
+}
+
+/// Returns whether the statement is the declaration of a variable initialized by `require`.
+pub fn is_require(statement: &AnyJsStatement) -> bool {
+    statement
+        .as_js_variable_statement()
+        .is_some_and(|statement| require_source(statement).is_some())
+}
 
 pub fn collect_imports(module: &JsModule) -> Vec<JsImport> {
     module
@@ -14,39 +172,177 @@ pub fn collect_imports(module: &JsModule) -> Vec<JsImport> {
         .collect()
 }
 
+/// Returns the item as an import, including `import x = require('x')` but not the aliases of an
+/// entity such as `import log = console.log`, as they may refer to the declarations before them.
+pub fn as_import(item: &AnyJsModuleItem) -> Option<AnyImport> {
+    match item {
+        AnyJsModuleItem::JsImport(import) => Some(import.clone().into()),
+        AnyJsModuleItem::AnyJsStatement(AnyJsStatement::TsImportEqualsDeclaration(import))
+            if import.source_text().is_ok() =>
+        {
+            Some(import.clone().into())
+        }
+        _ => None,
+    }
+}
+
+/// Collects the imports, including `import x = require('x')`.
+pub fn collect_any_imports(items: &JsModuleItemList) -> Vec<AnyImport> {
+    items
+        .into_iter()
+        .filter_map(|item| as_import(&item))
+        .collect()
+}
+
+/// Collects the re-exports, e.g. `export { foo } from './foo'` or `export * from './bar'`.
+pub fn collect_exports(items: &JsModuleItemList) -> Vec<JsExport> {
+    items
+        .into_iter()
+        .filter_map(|item| match item {
+            AnyJsModuleItem::JsExport(export) => Some(export),
+            _ => None,
+        })
+        .filter(is_re_export)
+        .collect()
+}
+
+/// Returns whether the export re-exports from another module.
+pub fn is_re_export(export: &JsExport) -> bool {
+    matches!(
+        export.export_clause(),
+        Ok(AnyJsExportClause::JsExportFromClause(_)
+            | AnyJsExportClause::JsExportNamedFromClause(_))
+    )
+}
+
+/// Returns whether the import is only for the side effects, e.g. `import './polyfills'`.
+pub fn is_side_effect(import: &AnyImport) -> bool {
+    matches!(
+        import,
+        AnyImport::JsImport(import)
+            if matches!(import.import_clause(), Ok(AnyJsImportClause::JsImportBareClause(_)))
+    )
+}
+
+/// Returns the key to find the named imports that can be merged into one.
+fn merge_key(import: &AnyImport) -> Option<(String, bool, Option<String>)> {
+    let AnyImport::JsImport(import) = import else {
+        return None;
+    };
+
+    let AnyJsImportClause::JsImportNamedClause(clause) = import.import_clause().ok()? else {
+        return None;
+    };
+
+    Some((
+        import.source_text().ok()?.to_string(),
+        clause.type_token().is_some(),
+        clause
+            .assertion()
+            .map(|assertion| assertion.syntax().text_trimmed().to_string()),
+    ))
+}
+
 #[derive(Clone, Debug)]
-pub struct ImportGroup {
-    kind: ImportKind,
-    items: Vec<JsImport>,
+pub struct ImportGroup<T = AnyImport> {
+    /// The index of the group in the configuration, and whether it is the sub-group of the
+    /// type-only imports.
+    index: (usize, bool),
+    items: Vec<T>,
 }
 
-impl ImportGroup {
-    fn new(kind: ImportKind, items: Vec<JsImport>) -> Self {
-        Self { kind, items }
+impl<T: ImportLike> ImportGroup<T> {
+    fn new(index: (usize, bool), items: Vec<T>) -> Self {
+        Self { index, items }
     }
 
-    pub fn items(&self) -> impl Iterator<Item = &JsImport> {
+    pub fn items(&self) -> impl Iterator<Item = &T> {
         self.items.iter()
     }
 
-    pub fn reorder_in_place(&mut self) {
-        self.items
-            .sort_by_key(|import| import.source_text().unwrap().to_string())
+    pub fn reorder_in_place(&mut self, config: &Config)
+    where
+        T: Remake,
+    {
+        // Computes the keys once, as the lengths need the imports to be remade.
+        let key = |import: &T| SortKey {
+            name: import.source_text().unwrap().to_string(),
+            len: if config.sort_order == SortOrder::LineLength {
+                import
+                    .remake(config)
+                    .map_or(0, |import| import.syntax().text_trimmed().len().into())
+            } else {
+                0
+            },
+            type_only: import.is_type_only(),
+        };
+
+        let mut items = self
+            .items
+            .drain(..)
+            .map(|import| (key(&import), import))
+            .collect::<Vec<_>>();
+
+        items.sort_by(|(a, _), (b, _)| config.compare(a, b));
+        self.items = items.into_iter().map(|(_, import)| import).collect();
+    }
+}
+
+impl ImportGroup<AnyImport> {
+    /// Merges the named imports from the same module into one.
+    /// Type-only imports are merged separately from the value imports.
+    pub fn merge_duplicates(&mut self) {
+        let mut items = Vec::<AnyImport>::new();
+        let mut indices = HashMap::new();
+
+        for import in self.items.drain(..) {
+            let Some(key) = merge_key(&import) else {
+                items.push(import);
+                continue;
+            };
+
+            if let Some(&index) = indices.get(&key) {
+                if let (AnyImport::JsImport(first), AnyImport::JsImport(second)) =
+                    (&items[index], &import)
+                {
+                    items[index] = merge_named_imports(first, second).unwrap().into();
+                }
+            } else {
+                indices.insert(key, items.len());
+                items.push(import);
+            }
+        }
+
+        self.items = items;
     }
 }
 
-pub fn group_imports(imports: impl IntoIterator<Item = JsImport>) -> Vec<ImportGroup> {
+pub fn group_imports<T: ImportLike>(
+    imports: impl IntoIterator<Item = T>,
+    config: &Config,
+    resolver: &Resolver,
+) -> Vec<ImportGroup<T>> {
     imports
         .into_iter()
-        .into_group_map_by(|import| ImportKind::guess(import.source_text().unwrap().text()))
+        .into_group_map_by(|import| {
+            let source = import.source_text().unwrap();
+            let type_only = import.is_type_only();
+
+            (
+                config.group_index(source.text(), resolver.kind_of(source.text()), type_only),
+                config.type_subgroups && type_only,
+            )
+        })
         .into_iter()
-        .map(|(kind, imports)| ImportGroup::new(kind, imports))
+        .map(|(index, imports)| ImportGroup::new(index, imports))
         .collect()
 }
 
-pub fn order_groups(groups: impl IntoIterator<Item = ImportGroup>) -> Vec<ImportGroup> {
+pub fn order_groups<T: ImportLike>(
+    groups: impl IntoIterator<Item = ImportGroup<T>>,
+) -> Vec<ImportGroup<T>> {
     groups
         .into_iter()
-        .sorted_by_key(|group| group.kind)
+        .sorted_by_key(|group| group.index)
         .collect()
 }
@@ -0,0 +1,237 @@
+use std::cmp::Ordering;
+use std::fs::read_to_string;
+use std::path::{Path, PathBuf};
+
+use glob::Pattern;
+use regex::Regex;
+use serde::de::Error as _;
+use serde::{Deserialize, Deserializer};
+
+use crate::import_kind::ImportKind;
+use crate::sort_order::{SortKey, SortOrder, TypeOrder};
+
+pub const CONFIG_FILE_NAME: &str = "tsimports.json";
+
+#[derive(Debug, thiserror::Error)]
+pub enum ConfigError {
+    #[error("Failed to read the configuration file {0}.")]
+    Io(PathBuf, #[source] std::io::Error),
+
+    #[error("Failed to parse the configuration file {0}: {1}")]
+    Json(PathBuf, #[source] serde_json::Error),
+
+    #[error("Failed to parse the configuration file {0}: {1}")]
+    Json5(PathBuf, #[source] json5::Error),
+
+    #[error("Failed to parse the configuration file {0}: {1}")]
+    Yaml(PathBuf, #[source] serde_yaml::Error),
+}
+
+fn deserialize_pattern<'de, D>(deserializer: D) -> Result<Pattern, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Pattern::new(&String::deserialize(deserializer)?).map_err(D::Error::custom)
+}
+
+fn deserialize_regex<'de, D>(deserializer: D) -> Result<Regex, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Regex::new(&String::deserialize(deserializer)?).map_err(D::Error::custom)
+}
+
+/// A preference of the quotes of the module specifiers and the import attributes.
+#[derive(Clone, Copy, Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum QuoteStyle {
+    /// Keeps the quotes as written.
+    #[default]
+    Preserve,
+
+    /// Uses single quotes, e.g. `'react'`.
+    Single,
+
+    /// Uses double quotes, e.g. `"react"`.
+    Double,
+}
+
+/// A policy of the semicolons at the end of the imports and the re-exports.
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum Semicolons {
+    /// Keeps the semicolons as written.
+    #[default]
+    Preserve,
+
+    /// Always ends the statements with semicolons.
+    Always,
+
+    /// Removes the semicolons, unless the statement after them would continue the last one.
+    Never,
+}
+
+/// A style of the type-only named imports.
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum TypeImportStyle {
+    /// Keeps the imports as written.
+    #[default]
+    Preserve,
+
+    /// Moves the inline `type` specifiers into `import type` statements, e.g.
+    /// `import type { A } from 'a'`.
+    Separate,
+}
+
+/// A definition of an import group, matching the module specifiers of the imports.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum GroupConfig {
+    /// Matches the imports of the kind, e.g. `{ "kind": "external" }`.
+    Kind(ImportKind),
+
+    /// Matches the imports by a glob pattern, e.g. `{ "glob": "@company/**" }`.
+    Glob(#[serde(deserialize_with = "deserialize_pattern")] Pattern),
+
+    /// Matches the imports by a regular expression, e.g. `{ "regex": "^@company/" }`.
+    Regex(#[serde(deserialize_with = "deserialize_regex")] Regex),
+
+    /// Matches the type-only imports of any kind, e.g. `"type"`.
+    Type,
+}
+
+impl GroupConfig {
+    fn matches_pattern(&self, source: &str) -> bool {
+        match self {
+            Self::Kind(_) | Self::Type => false,
+            Self::Glob(pattern) => pattern.matches(source),
+            Self::Regex(regex) => regex.is_match(source),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "camelCase")]
+pub struct Config {
+    /// Groups of the imports, in the order of the output.
+    pub groups: Vec<GroupConfig>,
+
+    /// Classifies the bare imports by the dependencies in `package.json` and the workspace packages.
+    pub resolve_packages: bool,
+
+    /// Merges the named imports from the same module into one.
+    pub merge_duplicates: bool,
+
+    /// Keeps the side-effect imports in place, sorting the imports between them independently.
+    pub side_effect_barriers: bool,
+
+    /// Organizes each run of adjacent imports in place, instead of collecting them at the top.
+    pub contiguous_blocks: bool,
+
+    /// Quotes of the module specifiers and the import attributes.
+    pub quote_style: QuoteStyle,
+
+    /// Semicolons at the end of the imports and the re-exports.
+    pub semicolons: Semicolons,
+
+    /// Maximum length of the statements, beyond which the named specifiers are put one per line.
+    pub print_width: Option<usize>,
+
+    /// Converts the imports of empty named specifiers, e.g. `import {} from 'a'`, into side-effect
+    /// imports, and removes the empty specifiers alongside default imports.
+    pub drop_empty_named_imports: bool,
+
+    /// Order of the imports in each group, and the specifiers in each import.
+    pub sort_order: SortOrder,
+
+    /// Position of the type-only imports and specifiers, relative to the value ones.
+    pub type_order: TypeOrder,
+
+    /// Splits each group into the value imports and the type-only imports after them.
+    pub type_subgroups: bool,
+
+    /// Style of the type-only named imports.
+    pub type_import_style: TypeImportStyle,
+
+    /// Removes the imported bindings that are never referenced.
+    pub remove_unused: bool,
+
+    /// Converts the imported bindings referenced only in type positions into type-only imports.
+    pub convert_type_only_imports: bool,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            groups: ImportKind::ALL.map(GroupConfig::Kind).to_vec(),
+            resolve_packages: false,
+            merge_duplicates: false,
+            side_effect_barriers: false,
+            contiguous_blocks: false,
+            quote_style: QuoteStyle::default(),
+            semicolons: Semicolons::default(),
+            print_width: None,
+            drop_empty_named_imports: false,
+            sort_order: SortOrder::default(),
+            type_order: TypeOrder::default(),
+            type_subgroups: false,
+            type_import_style: TypeImportStyle::default(),
+            remove_unused: false,
+            convert_type_only_imports: false,
+        }
+    }
+}
+
+impl Config {
+    /// Loads the configuration from the file.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, ConfigError> {
+        let path = path.as_ref();
+        let text = read_to_string(path).map_err(|e| ConfigError::Io(path.to_path_buf(), e))?;
+
+        serde_json::from_str(&text).map_err(|e| ConfigError::Json(path.to_path_buf(), e))
+    }
+
+    /// Finds the nearest configuration file by walking up from the directory and loads it.
+    /// Returns the default configuration if no configuration file was found.
+    pub fn discover(dir: impl AsRef<Path>) -> Result<Self, ConfigError> {
+        for dir in dir.as_ref().ancestors() {
+            let path = dir.join(CONFIG_FILE_NAME);
+            if path.is_file() {
+                return Self::load(path);
+            }
+        }
+
+        Ok(Self::default())
+    }
+
+    /// Compares the imports or the specifiers by the type order and then the sort order.
+    pub(crate) fn compare(&self, a: &SortKey, b: &SortKey) -> Ordering {
+        self.type_order
+            .compare(a, b)
+            .then_with(|| self.sort_order.compare(a, b))
+    }
+
+    /// Returns the index of the group that the import belongs to.
+    ///
+    /// The group of the type-only imports takes precedence over the others, and the groups matching
+    /// by patterns take precedence over the groups matching by kinds, so that the imports can be
+    /// split off from the group of their kind. Imports matching no group are put at the end of the
+    /// list.
+    pub(crate) fn group_index(&self, source: &str, kind: ImportKind, type_only: bool) -> usize {
+        self.groups
+            .iter()
+            .position(|group| type_only && matches!(group, GroupConfig::Type))
+            .or_else(|| {
+                self.groups
+                    .iter()
+                    .position(|group| group.matches_pattern(source))
+            })
+            .or_else(|| {
+                self.groups
+                    .iter()
+                    .position(|group| matches!(group, GroupConfig::Kind(k) if *k == kind))
+            })
+            .unwrap_or(self.groups.len())
+    }
+}
@@ -0,0 +1,150 @@
+use biome_js_syntax::{
+    JsDirectiveList, JsLanguage, JsModule, JsScript, JsSyntaxNode, JsSyntaxToken,
+};
+use biome_rowan::{AstNode, BatchMutationExt, TextSize};
+
+/// A root of the syntax tree, which can start with a header.
+pub trait Root: AstNode<Language = JsLanguage> + Clone {
+    fn bom_token(&self) -> Option<JsSyntaxToken>;
+    fn interpreter_token(&self) -> Option<JsSyntaxToken>;
+    fn directives(&self) -> JsDirectiveList;
+
+    /// Returns the list of the statements after the directives.
+    fn body(&self) -> JsSyntaxNode;
+}
+
+impl Root for JsModule {
+    fn bom_token(&self) -> Option<JsSyntaxToken> {
+        JsModule::bom_token(self)
+    }
+
+    fn interpreter_token(&self) -> Option<JsSyntaxToken> {
+        JsModule::interpreter_token(self)
+    }
+
+    fn directives(&self) -> JsDirectiveList {
+        JsModule::directives(self)
+    }
+
+    fn body(&self) -> JsSyntaxNode {
+        self.items().syntax().clone()
+    }
+}
+
+impl Root for JsScript {
+    fn bom_token(&self) -> Option<JsSyntaxToken> {
+        JsScript::bom_token(self)
+    }
+
+    fn interpreter_token(&self) -> Option<JsSyntaxToken> {
+        JsScript::interpreter_token(self)
+    }
+
+    fn directives(&self) -> JsDirectiveList {
+        JsScript::directives(self)
+    }
+
+    fn body(&self) -> JsSyntaxNode {
+        self.statements().syntax().clone()
+    }
+}
+
+/// Returns whether the comment is a triple-slash directive, e.g. `/// <reference types="node" />`.
+fn is_triple_slash_directive(comment: &str) -> bool {
+    comment
+        .strip_prefix("///")
+        .is_some_and(|directive| directive.trim_start().starts_with('<'))
+}
+
+/// Returns whether the comment is a license that bundlers and minifiers preserve, e.g.
+/// `/*! ... */` or a comment with `@license`.
+fn is_license(comment: &str) -> bool {
+    comment.starts_with("/*!")
+        || ["@license", "@preserve", "@copyright"]
+            .iter()
+            .any(|tag| comment.contains(tag))
+}
+
+/// Counts the leading trivia pieces of the token that are separated from it by a blank line, or that
+/// precede the last triple-slash directive. The licenses at the start are counted regardless of the
+/// blank lines.
+fn count_detached_trivia(token: &JsSyntaxToken) -> usize {
+    let mut count = 0;
+    let mut newlines = 0;
+    // Whether the pieces since the last counted one are all whitespaces.
+    let mut leading = true;
+
+    for (index, piece) in token.leading_trivia().pieces().enumerate() {
+        if piece.is_comments()
+            && (is_triple_slash_directive(piece.text()) || (leading && is_license(piece.text())))
+        {
+            count = index + 1;
+            newlines = 0;
+        } else if piece.is_newline() {
+            newlines += 1;
+            if newlines >= 2 {
+                count = index + 1;
+                leading = true;
+            }
+        } else if !piece.is_whitespace() {
+            newlines = 0;
+            leading = false;
+        }
+    }
+
+    count
+}
+
+/// Detaches the header from the root and returns it as a text with the rest of the root.
+///
+/// The header consists of the byte order mark, the interpreter (e.g. `#!/usr/bin/env node`), the
+/// directives (e.g. `'use client'`), and the comments that are separated from the first statement
+/// by a blank line, such as license banners. The triple-slash directives (e.g.
+/// `/// <reference types="node" />`) are also in the header, as they must precede any statements.
+pub fn detach_header<R: Root>(module: R) -> (String, R) {
+    let token = module.body().first_token();
+    let count = token.as_ref().map_or(0, count_detached_trivia);
+
+    // Without any statements, the header ends at the body, leaving the comments after the
+    // directives to the end of the file.
+    let end = match &token {
+        Some(token) => token
+            .leading_trivia()
+            .pieces()
+            .take(count)
+            .fold(token.text_range().start(), |end, piece| {
+                end + piece.text_len()
+            }),
+        None => module.body().text_range().start(),
+    };
+
+    if end == TextSize::from(0) {
+        return (String::new(), module);
+    }
+
+    let header = module.syntax().to_string()[..usize::from(end)]
+        .trim_end()
+        .to_string();
+
+    let mut mutation = module.clone().begin();
+
+    if let Some(bom) = module.bom_token() {
+        mutation.remove_token(bom);
+    }
+
+    if let Some(interpreter) = module.interpreter_token() {
+        mutation.remove_token(interpreter);
+    }
+
+    for directive in module.directives() {
+        mutation.remove_node(directive);
+    }
+
+    if let Some(token) = token {
+        let pieces = token.leading_trivia().pieces().skip(count);
+        mutation
+            .replace_token_discard_trivia(token.clone(), token.with_leading_trivia_pieces(pieces));
+    }
+
+    (header, R::unwrap_cast(mutation.commit()))
+}
@@ -54,10 +54,12 @@ const NODE_BUILTIN_MODULES: [&str; 53] = [
     "zlib",
 ];
 
-#[derive(Copy, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[derive(Copy, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum ImportKind {
     Builtin,
     External,
+    Workspace,
     Internal,
     Parent,
     Sibling,
@@ -65,6 +67,16 @@ pub enum ImportKind {
 }
 
 impl ImportKind {
+    pub const ALL: [Self; 7] = [
+        Self::Builtin,
+        Self::External,
+        Self::Workspace,
+        Self::Internal,
+        Self::Parent,
+        Self::Sibling,
+        Self::Index,
+    ];
+
     pub fn guess(name: &str) -> Self {
         if name == "bun" || name.starts_with("node:") {
             return Self::Builtin;
@@ -86,12 +98,7 @@ impl ImportKind {
             return Self::Parent;
         }
 
-        let mut chars = name.chars();
-        loop {
-            let Some(char) = chars.next() else {
-                break;
-            };
-
+        for char in name.chars() {
             if char == '@' {
                 continue;
             }
@@ -1,64 +1,494 @@
 mod analyze;
+mod config;
+mod header;
 mod import_kind;
+mod package;
+mod resolver;
+mod sort_order;
 mod transform;
+mod tsconfig;
+mod type_only;
+mod unused;
 
 use biome_js_parser::{parse, JsParserOptions};
-use biome_js_syntax::{AnyJsRoot, JsFileSource};
+use biome_js_syntax::{
+    AnyJsModuleItem, AnyJsRoot, JsExport, JsFileSource, JsModuleItemList, JsScript, JsSyntaxKind,
+    JsSyntaxNode, TsModuleBlock,
+};
 use biome_parser::diagnostic::ParseDiagnostic;
-use biome_rowan::{AstNode, BatchMutationExt};
+use biome_rowan::{AstNode, AstNodeList, BatchMutationExt};
 use itertools::Itertools;
 
-use crate::analyze::{collect_imports, group_imports, order_groups};
-use crate::transform::Remake;
+use crate::analyze::{
+    as_import, collect_any_imports, collect_exports, group_imports, is_re_export, is_require,
+    is_side_effect, order_groups, AnyImport, ImportGroup, ImportLike,
+};
+use crate::header::detach_header;
+use crate::transform::{
+    is_asi_hazard, leading_whitespace, separate_type_specifiers, starts_line, Remake,
+};
+use crate::type_only::convert_type_only_imports;
+use crate::unused::{remove_unused_imports, JsxFactories};
+
+pub use crate::config::{
+    Config, ConfigError, GroupConfig, QuoteStyle, Semicolons, TypeImportStyle, CONFIG_FILE_NAME,
+};
+pub use crate::import_kind::ImportKind;
+pub use crate::package::{is_commonjs, Packages};
+pub use crate::resolver::Resolver;
+pub use crate::sort_order::{SortOrder, TypeOrder};
+pub use crate::tsconfig::TsConfig;
 
 #[derive(Clone, Debug, thiserror::Error)]
 pub enum Error {
     #[error("Failed to parse the text as a ECMAScript or TypeScript module.")]
     Parser(Vec<ParseDiagnostic>),
 
-    #[error("Only module files (.mjs) or TypeScript files (.ts) are supported.")]
+    #[error(
+        "Only module files (.mjs), script files (.cjs) or TypeScript files (.ts) are supported."
+    )]
     NotJSModule,
 }
 
-pub fn tsimports<'a>(input: impl Into<&'a str>, source: JsFileSource) -> Result<String, Error> {
-    let root = parse(input.into(), source, JsParserOptions::default())
-        .ok()
-        .map_err(Error::Parser)?;
+/// Converts the type-only named imports into the configured style.
+fn convert_type_imports(imports: Vec<AnyImport>, config: &Config) -> Vec<AnyImport> {
+    match config.type_import_style {
+        TypeImportStyle::Preserve => imports,
+        TypeImportStyle::Separate => imports
+            .into_iter()
+            .flat_map(|import| match import {
+                AnyImport::JsImport(import) => separate_type_specifiers(import.clone())
+                    .unwrap_or_else(|_| vec![import])
+                    .into_iter()
+                    .map(Into::into)
+                    .collect(),
+                _ => vec![import],
+            })
+            .collect(),
+    }
+}
 
-    let AnyJsRoot::JsModule(root) = root else {
-        return Err(Error::NotJSModule);
-    };
+/// Groups and sorts the imports, and returns them as a text. If `terminated` is set, the last
+/// import ends with a semicolon regardless of the semicolon policy.
+fn organize(
+    imports: Vec<AnyImport>,
+    config: &Config,
+    resolver: &Resolver,
+    terminated: bool,
+) -> String {
+    let imports = convert_type_imports(imports, config);
+    if !config.side_effect_barriers {
+        return organize_groups(imports, config, resolver, terminated);
+    }
 
-    let mut groups = order_groups(group_imports(collect_imports(&root)));
+    let chunks = imports
+        .into_iter()
+        .chunk_by(is_side_effect)
+        .into_iter()
+        .map(|(side_effect, chunk)| (side_effect, chunk.collect::<Vec<_>>()))
+        .collect::<Vec<_>>();
+    let len = chunks.len();
 
-    groups.iter_mut().for_each(|group| group.reorder_in_place());
+    chunks
+        .into_iter()
+        .enumerate()
+        .map(|(index, (side_effect, chunk))| {
+            let terminated = terminated && index + 1 == len;
+            if side_effect {
+                let len = chunk.len();
+                chunk
+                    .iter()
+                    .enumerate()
+                    .map(|(index, item)| remake_text(item, config, terminated && index + 1 == len))
+                    .join("\n")
+            } else {
+                organize_groups(chunk, config, resolver, terminated)
+            }
+        })
+        .join("\n\n")
+}
 
-    let mut mutation = root.begin();
+fn organize_groups(
+    imports: Vec<AnyImport>,
+    config: &Config,
+    resolver: &Resolver,
+    terminated: bool,
+) -> String {
+    let mut groups = order_groups(group_imports(imports, config, resolver));
 
-    for group in &groups {
-        for item in group.items() {
-            mutation.remove_node(item.to_owned());
-        }
+    if config.merge_duplicates {
+        groups.iter_mut().for_each(|group| group.merge_duplicates());
     }
 
-    let imports = groups
+    render(groups, config, terminated)
+}
+
+/// Groups and sorts the re-exports, and returns them as a text.
+fn organize_exports(
+    exports: Vec<JsExport>,
+    config: &Config,
+    resolver: &Resolver,
+    terminated: bool,
+) -> String {
+    render(
+        order_groups(group_imports(exports, config, resolver)),
+        config,
+        terminated,
+    )
+}
+
+/// Sorts the items in each group, and returns them as a text.
+fn render<T: ImportLike + Remake>(
+    mut groups: Vec<ImportGroup<T>>,
+    config: &Config,
+    terminated: bool,
+) -> String {
+    groups
+        .iter_mut()
+        .for_each(|group| group.reorder_in_place(config));
+
+    let len = groups
+        .iter()
+        .map(|group| group.items().count())
+        .sum::<usize>();
+    let mut index = 0;
+
+    groups
         .into_iter()
         .map(|group| {
             group
                 .items()
-                .map(|item| item.remake().unwrap().text())
+                .map(|item| {
+                    index += 1;
+                    remake_text(item, config, terminated && index == len)
+                })
                 .join("\n")
         })
-        .join("\n\n");
-
-    Ok([
-        imports,
-        mutation
-            .commit()
-            .trim_leading_trivia()
-            .unwrap()
-            .text()
-            .to_string(),
+        .join("\n\n")
+}
+
+/// Remakes the item, and returns it as a text.
+fn remake_text<T: AstNode + Remake>(item: &T, config: &Config, terminated: bool) -> String {
+    let item = if terminated && config.semicolons == Semicolons::Never {
+        item.remake(&Config {
+            semicolons: Semicolons::Always,
+            ..config.clone()
+        })
+    } else {
+        item.remake(config)
+    };
+
+    item.unwrap().syntax().to_string()
+}
+
+/// Returns whether the statement following the organized ones needs them to end with a semicolon.
+fn needs_terminator(next: Option<&AnyJsModuleItem>, config: &Config) -> bool {
+    config.semicolons == Semicolons::Never && next.is_some_and(is_asi_hazard)
+}
+
+/// Indents each non-empty line of the text.
+fn indent_lines(text: &str, indent: &str) -> String {
+    text.lines()
+        .map(|line| {
+            if line.is_empty() {
+                String::new()
+            } else {
+                format!("{indent}{line}")
+            }
+        })
+        .join("\n")
+}
+
+/// Organizes the imports and the re-exports, collecting them at the top of the items. The `tail`
+/// is the text following the items, e.g. the comments at the end of the file.
+fn organize_hoisted(
+    items: JsModuleItemList,
+    indent: &str,
+    tail: &str,
+    config: &Config,
+    resolver: &Resolver,
+) -> String {
+    // Detaches the items, so that the mutation commits them rather than the whole tree.
+    let items = JsModuleItemList::unwrap_cast(items.into_syntax().clone_subtree());
+    let imports = collect_any_imports(&items);
+    let exports = collect_exports(&items);
+    let first = items.iter().find(|item| match item {
+        AnyJsModuleItem::JsExport(export) => !is_re_export(export),
+        _ => as_import(item).is_none(),
+    });
+    let terminated = needs_terminator(first.as_ref(), config);
+    let mut mutation = items.begin();
+
+    for import in &imports {
+        mutation.remove_node(import.to_owned());
+    }
+
+    for export in &exports {
+        mutation.remove_node(export.to_owned());
+    }
+
+    let imports = organize(imports, config, resolver, terminated && exports.is_empty());
+    let exports = organize_exports(exports, config, resolver, terminated);
+    let body = organize_module_blocks(&mutation.commit(), config, resolver) + tail;
+    let body = body.trim();
+
+    [
+        indent_lines(&imports, indent),
+        indent_lines(&exports, indent),
+        if body.is_empty() {
+            String::new()
+        } else {
+            format!("{indent}{body}")
+        },
     ]
-    .join("\n\n"))
+    .into_iter()
+    .filter(|part| !part.is_empty())
+    .join("\n\n")
+    .trim()
+    .to_string()
+}
+
+/// Returns `Some(true)` for an import, `Some(false)` for a re-export, or `None` for any other item.
+fn run_of(item: &AnyJsModuleItem) -> Option<bool> {
+    match item {
+        AnyJsModuleItem::JsExport(export) if is_re_export(export) => Some(false),
+        _ => as_import(item).map(|_| true),
+    }
+}
+
+/// Organizes each run of adjacent imports or re-exports in place, leaving the other statements
+/// between them as is.
+fn organize_in_place(
+    items: JsModuleItemList,
+    indent: &str,
+    tail: &str,
+    config: &Config,
+    resolver: &Resolver,
+) -> String {
+    let items = items.into_iter().collect::<Vec<_>>();
+    let runs = items
+        .chunk_by(|a, b| run_of(a).is_some() && run_of(a) == run_of(b))
+        .collect::<Vec<_>>();
+    let mut output = String::new();
+    let mut after_run = false;
+
+    for (index, run) in runs.iter().enumerate() {
+        let Some(import) = run_of(&run[0]) else {
+            // Breaks the line after the rebuilt run, which would otherwise be followed on the
+            // same line without the whitespace in between.
+            let same_line = after_run && !starts_line(&run[0]);
+            if same_line {
+                output.push('\n');
+                output.push_str(indent);
+            }
+
+            run.iter().enumerate().for_each(|(index, item)| {
+                let text = organize_module_blocks(item.syntax(), config, resolver);
+                output.push_str(if index == 0 && same_line {
+                    text.trim_start()
+                } else {
+                    &text
+                });
+            });
+            after_run = false;
+            continue;
+        };
+
+        // Separates the imports and the re-exports by a blank line, as when they are hoisted.
+        if after_run {
+            output.push_str("\n\n");
+            output.push_str(indent);
+        } else {
+            output.push_str(&leading_whitespace(run[0].syntax()));
+        }
+
+        after_run = true;
+
+        // Keeps the semicolon before a statement that followed the run on the same line.
+        let next = runs.get(index + 1).map(|next| &next[0]);
+        let terminated = needs_terminator(next, config)
+            || (config.semicolons == Semicolons::Never
+                && next.is_some_and(|next| !starts_line(next)));
+        let text = if import {
+            organize(
+                run.iter().filter_map(as_import).collect(),
+                config,
+                resolver,
+                terminated,
+            )
+        } else {
+            organize_exports(
+                run.iter()
+                    .filter_map(|item| item.as_js_export().cloned())
+                    .collect(),
+                config,
+                resolver,
+                terminated,
+            )
+        };
+
+        output.push_str(indent_lines(&text, indent).trim_start());
+    }
+
+    output.push_str(tail);
+    output.trim().to_string()
+}
+
+/// Organizes the items of the module, or of the body of a `declare module` or a namespace.
+fn organize_items(
+    items: JsModuleItemList,
+    indent: &str,
+    tail: &str,
+    config: &Config,
+    resolver: &Resolver,
+) -> String {
+    if config.contiguous_blocks {
+        organize_in_place(items, indent, tail, config, resolver)
+    } else {
+        organize_hoisted(items, indent, tail, config, resolver)
+    }
+}
+
+/// Returns the text of the node, organizing the imports in the outermost bodies of the
+/// `declare module` and the namespaces in it independently. The inner bodies are organized
+/// recursively.
+fn organize_module_blocks(node: &JsSyntaxNode, config: &Config, resolver: &Resolver) -> String {
+    let start = node.text_range().start();
+    let blocks = node
+        .descendants()
+        .filter_map(TsModuleBlock::cast)
+        .filter(|block| {
+            block
+                .syntax()
+                .ancestors()
+                .skip(1)
+                .take_while(|ancestor| ancestor != node)
+                .all(|ancestor| ancestor.kind() != JsSyntaxKind::TS_MODULE_BLOCK)
+        })
+        .collect::<Vec<_>>();
+    let mut text = node.to_string();
+
+    for block in blocks.into_iter().rev() {
+        let items = block.items();
+        let range = items.syntax().text_range() - start;
+
+        // Leaves the blocks without imports as they are, except for the blocks nested in them.
+        let organized = if items.iter().any(|item| run_of(&item).is_some()) {
+            let original = items.syntax().to_string();
+            let whitespace = items
+                .first()
+                .map(|item| leading_whitespace(item.syntax()))
+                .unwrap_or_default();
+            let indent = whitespace.rsplit('\n').next().unwrap_or_default();
+            let trailing = &original[original.trim_end().len()..];
+
+            format!(
+                "{whitespace}{}{trailing}",
+                organize_items(items, indent, "", config, resolver)
+            )
+        } else {
+            organize_module_blocks(items.syntax(), config, resolver)
+        };
+
+        text.replace_range(
+            usize::from(range.start())..usize::from(range.end()),
+            &organized,
+        );
+    }
+
+    text
+}
+
+/// Organizes each run of adjacent `require` declarations in place. Unlike imports, they are not
+/// collected at the top, as it would change the order of execution.
+fn organize_script(root: &JsScript, config: &Config, resolver: &Resolver) -> String {
+    let statements = root.statements().into_iter().collect::<Vec<_>>();
+    let mut output = String::new();
+
+    for run in statements.chunk_by(|a, b| is_require(a) && is_require(b)) {
+        if !is_require(&run[0]) {
+            run.iter()
+                .for_each(|statement| output.push_str(&statement.syntax().to_string()));
+            continue;
+        }
+
+        let requires = run
+            .iter()
+            .filter_map(|statement| statement.as_js_variable_statement().cloned())
+            .collect::<Vec<_>>();
+
+        output.push_str(&leading_whitespace(run[0].syntax()));
+        output.push_str(&render(
+            order_groups(group_imports(requires, config, resolver)),
+            config,
+            false,
+        ));
+    }
+
+    if let Ok(eof) = root.eof_token() {
+        output.push_str(&eof.to_string());
+    }
+
+    output.trim().to_string()
+}
+
+pub fn tsimports<'a>(input: impl Into<&'a str>, source: JsFileSource) -> Result<String, Error> {
+    tsimports_with_config(input, source, &Config::default(), &Resolver::default())
+}
+
+pub fn tsimports_with_config<'a>(
+    input: impl Into<&'a str>,
+    source: JsFileSource,
+    config: &Config,
+    resolver: &Resolver,
+) -> Result<String, Error> {
+    let root = parse(input.into(), source, JsParserOptions::default())
+        .ok()
+        .map_err(Error::Parser)?;
+
+    let root = match root {
+        AnyJsRoot::JsModule(root) => root,
+        AnyJsRoot::JsScript(root) => {
+            let (header, root) = detach_header(root);
+            let body = organize_script(&root, config, resolver);
+
+            return Ok(join_parts([header, body]));
+        }
+        _ => return Err(Error::NotJSModule),
+    };
+
+    // Reads the pragmas before detaching the header containing them.
+    let jsx = JsxFactories::read(&root, resolver.tsconfig());
+
+    // Detaches the header first, so that removing the first import doesn't remove it as well.
+    let (header, root) = detach_header(root);
+
+    let root = if config.remove_unused {
+        remove_unused_imports(root, &jsx)
+    } else {
+        root
+    };
+
+    let root = if config.convert_type_only_imports {
+        convert_type_only_imports(root)
+    } else {
+        root
+    };
+
+    let tail = root
+        .eof_token()
+        .map(|eof| eof.to_string())
+        .unwrap_or_default();
+    let body = organize_items(root.items(), "", &tail, config, resolver);
+
+    Ok(join_parts([header, body]))
+}
+
+/// Joins the non-empty parts of the output by blank lines.
+fn join_parts(parts: impl IntoIterator<Item = String>) -> String {
+    parts
+        .into_iter()
+        .filter(|part| !part.is_empty())
+        .join("\n\n")
+        + "\n"
 }
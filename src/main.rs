@@ -1,6 +1,9 @@
+use std::collections::HashMap;
+use std::env::current_dir;
 use std::fs::{read_to_string, write};
 use std::io::{stdin, Read};
-use std::path::{Path, PathBuf};
+use std::path::{absolute, Path, PathBuf};
+use std::rc::Rc;
 
 use anyhow::{anyhow, Result};
 use biome_console::{markup, ColorMode, Console, EnvConsole, LogLevel};
@@ -8,39 +11,54 @@ use biome_diagnostics::PrintDiagnostic;
 use biome_js_syntax::JsFileSource;
 use clap::{Parser, ValueEnum};
 use glob::glob;
-use tsimports::{tsimports, Error};
+use similar::{ChangeTag, TextDiff};
+use tsimports::{is_commonjs, tsimports_with_config, Config, Error, Resolver};
 use walkdir::WalkDir;
 
 #[derive(Copy, Clone, Debug, Default, ValueEnum)]
 enum Language {
     #[default]
-    JS,
-    JSX,
-    TS,
-    TSX,
+    Js,
+    Jsx,
+    Cjs,
+    Ts,
+    Tsx,
+    Dts,
 }
 
 impl Language {
     fn from_extension(ext: &str) -> Option<Self> {
         match ext {
-            "js" | "mjs" => Some(Self::JS),
-            "jsx" => Some(Self::JSX),
-            "ts" | "cts" | "mts" => Some(Self::TS),
-            "tsx" => Some(Self::TSX),
+            "js" | "mjs" => Some(Self::Js),
+            "jsx" => Some(Self::Jsx),
+            "cjs" => Some(Self::Cjs),
+            "ts" | "cts" | "mts" => Some(Self::Ts),
+            "tsx" => Some(Self::Tsx),
             _ => None,
         }
     }
 
     fn from_path(path: impl AsRef<Path>) -> Option<Self> {
-        Self::from_extension(path.as_ref().extension().and_then(|ext| ext.to_str())?)
+        let path = path.as_ref();
+        let name = path.file_name().and_then(|name| name.to_str())?;
+        if [".d.ts", ".d.mts", ".d.cts"]
+            .iter()
+            .any(|suffix| name.ends_with(suffix))
+        {
+            return Some(Self::Dts);
+        }
+
+        Self::from_extension(path.extension().and_then(|ext| ext.to_str())?)
     }
 
-    fn to_file_source(&self) -> JsFileSource {
+    fn to_file_source(self) -> JsFileSource {
         match self {
-            Self::JS => JsFileSource::js_module(),
-            Self::JSX => JsFileSource::jsx(),
-            Self::TS => JsFileSource::ts(),
-            Self::TSX => JsFileSource::tsx(),
+            Self::Js => JsFileSource::js_module(),
+            Self::Jsx => JsFileSource::jsx(),
+            Self::Cjs => JsFileSource::js_script(),
+            Self::Ts => JsFileSource::ts(),
+            Self::Tsx => JsFileSource::tsx(),
+            Self::Dts => JsFileSource::d_ts(),
         }
     }
 }
@@ -58,6 +76,18 @@ struct Args {
     /// Write the formatted result into the file directly, without printing to the standard output.
     #[clap(short, long)]
     write: bool,
+
+    /// Check whether the files are organized, without writing anything. Exits with 1 if not.
+    #[clap(long, conflicts_with = "write")]
+    check: bool,
+
+    /// Print the unified diff between the input and the organized result, without writing anything.
+    #[clap(long, conflicts_with = "write")]
+    diff: bool,
+
+    /// Remove the imported bindings that are never referenced, in addition to the configuration.
+    #[clap(long)]
+    remove_unused: bool,
 }
 
 fn main() {
@@ -66,36 +96,35 @@ fn main() {
         console.println(
             LogLevel::Error,
             markup! { <Error>"\u{2716} "{e.to_string()}</Error> },
-        )
+        );
+
+        std::process::exit(1);
     }
 }
 
 fn run(console: &mut impl Console) -> Result<()> {
     let args = Args::parse();
 
+    let mut inputs = Vec::new();
     if let Some(paths) = &args.paths {
         for path in paths.iter() {
             for entry in glob(path)? {
                 let entry = entry?;
                 if entry.is_dir() {
-                    WalkDir::new(entry)
-                        .into_iter()
-                        .filter_map(|e| e.ok())
-                        .filter_map(|e| {
-                            let path = e.path().to_path_buf();
-                            match Language::from_path(&path) {
-                                Some(lang) => Some((path, lang)),
-                                _ => None,
-                            }
-                        })
-                        .map(|(path, lang)| {
-                            run_single(Input::File(path, Some(lang)), &args, console)
-                        })
-                        .collect::<Result<()>>()?;
+                    inputs.extend(
+                        WalkDir::new(entry)
+                            .into_iter()
+                            .filter_map(|e| e.ok())
+                            .filter_map(|e| {
+                                let path = e.path().to_path_buf();
+                                Language::from_path(&path).map(|lang| (path, lang))
+                            })
+                            .map(|(path, lang)| Input::File(path, Some(lang))),
+                    );
                 } else {
                     let lang = Language::from_path(&entry);
 
-                    run_single(Input::File(entry, lang), &args, console)?;
+                    inputs.push(Input::File(entry, lang));
                 }
             }
         }
@@ -104,24 +133,150 @@ fn run(console: &mut impl Console) -> Result<()> {
             console.println(LogLevel::Error, markup! {
                 <Warn>
                     "\u{26a0} Input language is not specified, assuming as an ECMAScript module. Use "
-                    <Emphasis>"--language <js|jsx|ts|tsx>"</Emphasis>
+                    <Emphasis>"--language <js|jsx|cjs|ts|tsx|dts>"</Emphasis>
                     " option to override."
                 </Warn>
             });
         }
 
-        run_single(Input::Stdin, &args, console)?;
+        inputs.push(Input::Stdin);
+    }
+
+    let mut projects = Projects::default();
+    let mut unorganized = Vec::new();
+    let mut failed = 0;
+    for input in inputs {
+        let name = input.name();
+        match run_single(input, &args, &mut projects, console) {
+            Ok(true) => {}
+            Ok(false) => unorganized.push(name),
+            // Checks the rest of the files, so that all the failures are reported at once.
+            Err(e) if args.check => {
+                console.println(
+                    LogLevel::Error,
+                    markup! { <Error>"\u{2716} "{name}": "{e.to_string()}</Error> },
+                );
+
+                failed += 1;
+            }
+            Err(e) => return Err(e),
+        }
+    }
+
+    if args.check {
+        if failed > 0 {
+            console.println(
+                LogLevel::Error,
+                markup! { <Error>"\u{2716} "{failed}" file(s) could not be checked."</Error> },
+            );
+        }
+
+        if unorganized.is_empty() && failed == 0 {
+            console.println(
+                LogLevel::Error,
+                markup! { <Success>"\u{2714} All files are organized."</Success> },
+            );
+        } else if !unorganized.is_empty() {
+            for name in &unorganized {
+                console.println(
+                    LogLevel::Error,
+                    markup! { <Warn>"\u{26a0} "{name}" is not organized."</Warn> },
+                );
+            }
+
+            console.println(
+                LogLevel::Error,
+                markup! {
+                    <Error>
+                        "\u{2716} "{unorganized.len()}" file(s) are not organized. Run with "
+                        <Emphasis>"--write"</Emphasis>
+                        " option to organize them."
+                    </Error>
+                },
+            );
+        }
+
+        if !unorganized.is_empty() || failed > 0 {
+            std::process::exit(1);
+        }
     }
 
     Ok(())
 }
 
+fn print_diff(console: &mut impl Console, name: &str, old: &str, new: &str) {
+    console.println(
+        LogLevel::Log,
+        markup! { <Emphasis>"--- "{name}"\n+++ "{name}</Emphasis> },
+    );
+
+    let diff = TextDiff::from_lines(old, new);
+    for hunk in diff.unified_diff().context_radius(3).iter_hunks() {
+        let header = hunk.header().to_string();
+        console.println(LogLevel::Log, markup! { <Info>{header}</Info> });
+
+        for change in hunk.iter_changes() {
+            let line = change.value().trim_end_matches(['\r', '\n']);
+            match change.tag() {
+                ChangeTag::Delete => {
+                    console.println(LogLevel::Log, markup! { <Error>"-"{line}</Error> })
+                }
+                ChangeTag::Insert => {
+                    console.println(LogLevel::Log, markup! { <Success>"+"{line}</Success> })
+                }
+                ChangeTag::Equal => console.println(LogLevel::Log, markup! { " "{line} }),
+            }
+        }
+    }
+}
+
 enum Input {
     File(PathBuf, Option<Language>),
     Stdin,
 }
 
-fn run_single(input: Input, args: &Args, console: &mut impl Console) -> Result<()> {
+impl Input {
+    fn name(&self) -> String {
+        match self {
+            Self::File(path, _) => path.display().to_string(),
+            Self::Stdin => "<stdin>".to_string(),
+        }
+    }
+}
+
+/// Caches the configuration, the resolver and whether the `.js` files are CommonJS for each
+/// directory, so that the files in the same directory don't read and parse the project files again.
+#[derive(Default)]
+struct Projects {
+    cache: HashMap<PathBuf, Rc<(Config, Resolver, bool)>>,
+}
+
+impl Projects {
+    fn discover(&mut self, dir: PathBuf, args: &Args) -> Result<Rc<(Config, Resolver, bool)>> {
+        if let Some(project) = self.cache.get(&dir) {
+            return Ok(project.clone());
+        }
+
+        let mut config = Config::discover(&dir)?;
+        config.remove_unused |= args.remove_unused;
+
+        let resolver = Resolver::discover(&dir, &config)?;
+        let commonjs = is_commonjs(&dir)?;
+        let project = Rc::new((config, resolver, commonjs));
+
+        self.cache.insert(dir, project.clone());
+
+        Ok(project)
+    }
+}
+
+/// Organizes the imports of the input, and returns whether the input was already organized.
+fn run_single(
+    input: Input,
+    args: &Args,
+    projects: &mut Projects,
+    console: &mut impl Console,
+) -> Result<bool> {
     let mut source = JsFileSource::js_module();
     let buf = match &input {
         Input::File(path, lang) => {
@@ -142,23 +297,56 @@ fn run_single(input: Input, args: &Args, console: &mut impl Console) -> Result<(
         source = lang.to_file_source();
     }
 
-    let output = match tsimports(buf.as_str(), source) {
+    // Makes the directory absolute, so that the discovery walks up beyond the current directory.
+    let dir = match &input {
+        Input::File(path, _) => absolute(
+            path.parent()
+                .filter(|parent| !parent.as_os_str().is_empty())
+                .unwrap_or(Path::new(".")),
+        )?,
+        _ => current_dir()?,
+    };
+
+    let project = projects.discover(dir, args)?;
+    let (config, resolver, commonjs) = project.as_ref();
+
+    // Like Node.js, `.js` files are scripts in a CommonJS package. As bundlers accept the module
+    // syntax in them anyway, the files that don't parse as scripts are parsed as modules instead.
+    let script = *commonjs
+        && args.language.is_none()
+        && matches!(&input, Input::File(path, _) if path.extension().is_some_and(|ext| ext == "js"));
+    let result = if script {
+        tsimports_with_config(buf.as_str(), JsFileSource::js_script(), config, resolver)
+            .or_else(|_| tsimports_with_config(buf.as_str(), source, config, resolver))
+    } else {
+        tsimports_with_config(buf.as_str(), source, config, resolver)
+    };
+
+    let output = match result {
         Ok(o) => o,
-        Err(e) => match e {
-            Error::Parser(diags) => {
+        Err(e) => {
+            if let Error::Parser(diags) = &e {
                 for diag in diags {
                     console.println(
                         LogLevel::Error,
-                        markup! { {PrintDiagnostic::verbose(&diag)} },
+                        markup! { {PrintDiagnostic::verbose(diag)} },
                     );
                 }
-
-                std::process::exit(1);
             }
-            _ => return Err(anyhow!(e)),
-        },
+
+            return Err(anyhow!(e));
+        }
     };
 
+    let organized = output == buf;
+    if args.diff && !organized {
+        print_diff(console, &input.name(), &buf, &output);
+    }
+
+    if args.check || args.diff {
+        return Ok(organized);
+    }
+
     if args.write {
         let Input::File(path, _) = input else {
             return Err(anyhow!(
@@ -171,5 +359,5 @@ fn run_single(input: Input, args: &Args, console: &mut impl Console) -> Result<(
         print!("{}", output);
     }
 
-    Ok(())
+    Ok(organized)
 }
@@ -0,0 +1,190 @@
+use std::collections::{HashMap, HashSet};
+use std::fs::read_to_string;
+use std::path::{Path, PathBuf};
+
+use glob::glob;
+use serde::de::IgnoredAny;
+use serde::Deserialize;
+
+use crate::config::ConfigError;
+
+const PACKAGE_JSON: &str = "package.json";
+const PNPM_WORKSPACE_YAML: &str = "pnpm-workspace.yaml";
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum RawWorkspaces {
+    Packages(Vec<String>),
+    Object { packages: Vec<String> },
+}
+
+#[derive(Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+struct RawPackageJson {
+    name: Option<String>,
+    #[serde(rename = "type")]
+    module_type: Option<String>,
+    dependencies: HashMap<String, IgnoredAny>,
+    dev_dependencies: HashMap<String, IgnoredAny>,
+    peer_dependencies: HashMap<String, IgnoredAny>,
+    optional_dependencies: HashMap<String, IgnoredAny>,
+    workspaces: Option<RawWorkspaces>,
+}
+
+impl RawPackageJson {
+    fn load(path: &Path) -> Result<Self, ConfigError> {
+        let text = read_to_string(path).map_err(|e| ConfigError::Io(path.to_path_buf(), e))?;
+
+        serde_json::from_str(&text).map_err(|e| ConfigError::Json(path.to_path_buf(), e))
+    }
+
+    fn workspaces(self) -> Option<Vec<String>> {
+        match self.workspaces? {
+            RawWorkspaces::Packages(packages) => Some(packages),
+            RawWorkspaces::Object { packages } => Some(packages),
+        }
+    }
+}
+
+#[derive(Default, Deserialize)]
+struct RawPnpmWorkspace {
+    #[serde(default)]
+    packages: Vec<String>,
+}
+
+impl RawPnpmWorkspace {
+    fn load(path: &Path) -> Result<Self, ConfigError> {
+        let text = read_to_string(path).map_err(|e| ConfigError::Io(path.to_path_buf(), e))?;
+
+        serde_yaml::from_str(&text).map_err(|e| ConfigError::Yaml(path.to_path_buf(), e))
+    }
+}
+
+/// Returns the package name of the bare module specifier, e.g. `@scope/name` for
+/// `@scope/name/sub/path`.
+fn package_name(source: &str) -> &str {
+    let end = source
+        .match_indices('/')
+        .nth(usize::from(source.starts_with('@')))
+        .map_or(source.len(), |(index, _)| index);
+
+    &source[..end]
+}
+
+/// Finds the workspace root by walking up from the directory, and returns the glob patterns of the
+/// workspace packages.
+fn discover_workspaces(dir: &Path) -> Result<Option<(PathBuf, Vec<String>)>, ConfigError> {
+    for dir in dir.ancestors() {
+        let path = dir.join(PNPM_WORKSPACE_YAML);
+        if path.is_file() {
+            let workspace = RawPnpmWorkspace::load(&path)?;
+            return Ok(Some((dir.to_path_buf(), workspace.packages)));
+        }
+
+        let path = dir.join(PACKAGE_JSON);
+        if path.is_file() {
+            if let Some(patterns) = RawPackageJson::load(&path)?.workspaces() {
+                return Ok(Some((dir.to_path_buf(), patterns)));
+            }
+        }
+    }
+
+    Ok(None)
+}
+
+/// Collects the names of the packages matching the workspace patterns.
+fn collect_workspace_packages(
+    root: &Path,
+    patterns: &[String],
+) -> Result<HashSet<String>, ConfigError> {
+    let mut dirs = HashSet::new();
+    for pattern in patterns {
+        let (negated, pattern) = match pattern.strip_prefix('!') {
+            Some(pattern) => (true, pattern),
+            None => (false, pattern.as_str()),
+        };
+
+        let pattern = root.join(pattern).join(PACKAGE_JSON);
+        let Ok(paths) = glob(&pattern.to_string_lossy()) else {
+            continue;
+        };
+
+        for path in paths.filter_map(|path| path.ok()) {
+            if negated {
+                dirs.remove(&path);
+            } else {
+                dirs.insert(path);
+            }
+        }
+    }
+
+    let mut names = HashSet::new();
+    for path in dirs {
+        if let Some(name) = RawPackageJson::load(&path)?.name {
+            names.insert(name);
+        }
+    }
+
+    Ok(names)
+}
+
+/// Returns whether the `.js` files in the directory are CommonJS, which is the case unless the
+/// nearest `package.json` has `"type": "module"`.
+pub fn is_commonjs(dir: impl AsRef<Path>) -> Result<bool, ConfigError> {
+    let Some(path) = dir
+        .as_ref()
+        .ancestors()
+        .map(|dir| dir.join(PACKAGE_JSON))
+        .find(|path| path.is_file())
+    else {
+        return Ok(true);
+    };
+
+    Ok(RawPackageJson::load(&path)?.module_type.as_deref() != Some("module"))
+}
+
+/// Declared dependencies of the nearest package and the packages in the workspace.
+#[derive(Clone, Debug, Default)]
+pub struct Packages {
+    dependencies: HashSet<String>,
+    workspaces: HashSet<String>,
+}
+
+impl Packages {
+    /// Finds the nearest `package.json` and the workspace root by walking up from the directory.
+    pub fn discover(dir: impl AsRef<Path>) -> Result<Self, ConfigError> {
+        let dir = dir.as_ref();
+        let mut packages = Self::default();
+
+        if let Some(path) = dir
+            .ancestors()
+            .map(|dir| dir.join(PACKAGE_JSON))
+            .find(|path| path.is_file())
+        {
+            let package = RawPackageJson::load(&path)?;
+            packages.dependencies = [
+                package.dependencies,
+                package.dev_dependencies,
+                package.peer_dependencies,
+                package.optional_dependencies,
+            ]
+            .into_iter()
+            .flat_map(|dependencies| dependencies.into_keys())
+            .collect();
+        }
+
+        if let Some((root, patterns)) = discover_workspaces(dir)? {
+            packages.workspaces = collect_workspace_packages(&root, &patterns)?;
+        }
+
+        Ok(packages)
+    }
+
+    pub fn is_dependency(&self, source: &str) -> bool {
+        self.dependencies.contains(package_name(source))
+    }
+
+    pub fn is_workspace(&self, source: &str) -> bool {
+        self.workspaces.contains(package_name(source))
+    }
+}
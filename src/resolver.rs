@@ -0,0 +1,72 @@
+use std::path::Path;
+
+use crate::config::{Config, ConfigError};
+use crate::import_kind::ImportKind;
+use crate::package::Packages;
+use crate::tsconfig::TsConfig;
+
+/// Classifies the imports using the project files in addition to the module specifiers.
+#[derive(Clone, Debug, Default)]
+pub struct Resolver {
+    tsconfig: Option<TsConfig>,
+    packages: Option<Packages>,
+}
+
+impl Resolver {
+    pub fn with_tsconfig(mut self, tsconfig: TsConfig) -> Self {
+        self.tsconfig = Some(tsconfig);
+        self
+    }
+
+    pub fn with_packages(mut self, packages: Packages) -> Self {
+        self.packages = Some(packages);
+        self
+    }
+
+    /// Finds the project files by walking up from the directory.
+    pub fn discover(dir: impl AsRef<Path>, config: &Config) -> Result<Self, ConfigError> {
+        let dir = dir.as_ref();
+
+        Ok(Self {
+            tsconfig: TsConfig::discover(dir)?,
+            packages: if config.resolve_packages {
+                Some(Packages::discover(dir)?)
+            } else {
+                None
+            },
+        })
+    }
+
+    pub fn tsconfig(&self) -> Option<&TsConfig> {
+        self.tsconfig.as_ref()
+    }
+
+    pub fn kind_of(&self, source: &str) -> ImportKind {
+        let kind = ImportKind::guess(source);
+        if !matches!(kind, ImportKind::External) {
+            return kind;
+        }
+
+        if let Some(packages) = &self.packages {
+            if packages.is_workspace(source) {
+                return ImportKind::Workspace;
+            }
+
+            if packages.is_dependency(source) {
+                return ImportKind::External;
+            }
+        }
+
+        if let Some(tsconfig) = &self.tsconfig {
+            if tsconfig.is_internal(source) {
+                return ImportKind::Internal;
+            }
+        }
+
+        if self.packages.is_some() {
+            ImportKind::Internal
+        } else {
+            kind
+        }
+    }
+}
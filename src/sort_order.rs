@@ -0,0 +1,122 @@
+use std::cmp::Ordering;
+use std::iter::Peekable;
+use std::str::Chars;
+
+use serde::Deserialize;
+
+/// A key to sort an import or a specifier by.
+#[derive(Clone, Debug)]
+pub struct SortKey {
+    /// The module specifier of the import, or the name of the specifier.
+    pub name: String,
+
+    /// The length of the import or the specifier as printed.
+    pub len: usize,
+
+    /// Whether the import or the specifier is type-only.
+    pub type_only: bool,
+}
+
+/// An order of the imports in each group, and the specifiers in each import.
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum SortOrder {
+    /// Compares the names by the code points, e.g. `Zod` before `axios`.
+    #[default]
+    Ascii,
+
+    /// Compares the names ignoring the case, e.g. `axios` before `Zod`.
+    CaseInsensitive,
+
+    /// Compares the names ignoring the case, and the numbers in them by their values, e.g. `file2`
+    /// before `file10`.
+    Natural,
+
+    /// Compares the lengths as printed, shorter first, and then the names by the code points.
+    LineLength,
+}
+
+/// A position of the type-only imports and specifiers, relative to the value ones.
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum TypeOrder {
+    /// Sorts the type-only items together with the value ones.
+    #[default]
+    Mixed,
+
+    /// Puts the type-only items before the value ones.
+    First,
+
+    /// Puts the type-only items after the value ones.
+    Last,
+}
+
+impl TypeOrder {
+    pub fn compare(self, a: &SortKey, b: &SortKey) -> Ordering {
+        match self {
+            Self::Mixed => Ordering::Equal,
+            Self::First => b.type_only.cmp(&a.type_only),
+            Self::Last => a.type_only.cmp(&b.type_only),
+        }
+    }
+}
+
+/// Consumes the digits at the head of the characters, and returns them without the leading zeros.
+fn take_number(chars: &mut Peekable<Chars>) -> String {
+    let mut number = String::new();
+    while let Some(char) = chars.next_if(char::is_ascii_digit) {
+        if !(number.is_empty() && char == '0') {
+            number.push(char);
+        }
+    }
+
+    number
+}
+
+fn compare_case_insensitive(a: &str, b: &str) -> Ordering {
+    a.chars()
+        .flat_map(char::to_lowercase)
+        .cmp(b.chars().flat_map(char::to_lowercase))
+}
+
+fn compare_natural(a: &str, b: &str) -> Ordering {
+    let mut a = a.chars().peekable();
+    let mut b = b.chars().peekable();
+
+    loop {
+        let ordering = match (a.peek(), b.peek()) {
+            (None, None) => return Ordering::Equal,
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+            (Some(x), Some(y)) if x.is_ascii_digit() && y.is_ascii_digit() => {
+                let x = take_number(&mut a);
+                let y = take_number(&mut b);
+
+                x.len().cmp(&y.len()).then_with(|| x.cmp(&y))
+            }
+            (Some(_), Some(_)) => {
+                let (x, y) = (a.next().unwrap(), b.next().unwrap());
+
+                x.to_lowercase().cmp(y.to_lowercase())
+            }
+        };
+
+        if ordering.is_ne() {
+            return ordering;
+        }
+    }
+}
+
+impl SortOrder {
+    /// Compares the keys, falling back to the code points of the names for ties.
+    pub fn compare(self, a: &SortKey, b: &SortKey) -> Ordering {
+        let ordering = match self {
+            Self::Ascii => Ordering::Equal,
+            Self::CaseInsensitive => compare_case_insensitive(&a.name, &b.name),
+            Self::Natural => compare_natural(&a.name, &b.name),
+            Self::LineLength => a.len.cmp(&b.len),
+        };
+
+        ordering.then_with(|| a.name.cmp(&b.name))
+    }
+}
@@ -1,13 +1,23 @@
 use biome_js_factory::make;
 use biome_js_syntax::{
-    AnyJsBinding, AnyJsCombinedSpecifier, AnyJsImportAssertionEntry, AnyJsImportClause,
-    AnyJsModuleSource, AnyJsNamedImportSpecifier, JsDefaultImportSpecifier, JsImport,
+    AnyJsBinding, AnyJsCombinedSpecifier, AnyJsExportClause, AnyJsImportAssertionEntry,
+    AnyJsImportClause, AnyJsModuleItem, AnyJsModuleSource, AnyJsNamedImportSpecifier,
+    AnyTsModuleReference, JsDefaultImportSpecifier, JsExport, JsExportAsClause, JsExportFromClause,
+    JsExportNamedFromClause, JsExportNamedFromSpecifier, JsExportNamedFromSpecifierList, JsImport,
     JsImportAssertion, JsImportAssertionEntryList, JsImportBareClause, JsImportCombinedClause,
-    JsImportDefaultClause, JsImportNamedClause, JsImportNamespaceClause, JsNamedImportSpecifier,
-    JsNamedImportSpecifierList, JsNamedImportSpecifiers, JsNamespaceImportSpecifier,
-    JsShorthandNamedImportSpecifier, JsSyntaxKind, JsSyntaxToken,
+    JsImportDefaultClause, JsImportNamedClause, JsImportNamespaceClause, JsLanguage,
+    JsLiteralExportName, JsNamedImportSpecifier, JsNamedImportSpecifierList,
+    JsNamedImportSpecifiers, JsNamespaceImportSpecifier, JsShorthandNamedImportSpecifier,
+    JsSyntaxKind, JsSyntaxNode, JsSyntaxToken, JsVariableStatement, TsExternalModuleReference,
+    TsImportEqualsDeclaration,
 };
-use biome_rowan::{AstSeparatedList, SyntaxResult, TriviaPiece};
+use biome_rowan::{
+    AstNode, AstSeparatedList, SyntaxResult, SyntaxTriviaPiece, TriviaPiece, TriviaPieceKind,
+};
+
+use crate::analyze::AnyImport;
+use crate::config::{Config, QuoteStyle, Semicolons};
+use crate::sort_order::SortKey;
 
 fn make_token_with_l_space(kind: JsSyntaxKind) -> JsSyntaxToken {
     if let Some(text) = kind.to_string() {
@@ -25,15 +35,318 @@ fn make_token_with_r_space(kind: JsSyntaxKind) -> JsSyntaxToken {
     }
 }
 
+/// Makes the curly braces of the named specifiers, spaced unless the specifiers are empty.
+fn make_curly_tokens(empty: bool) -> (JsSyntaxToken, JsSyntaxToken) {
+    if empty {
+        (
+            make::token(JsSyntaxKind::L_CURLY),
+            make::token(JsSyntaxKind::R_CURLY),
+        )
+    } else {
+        (
+            make_token_with_r_space(JsSyntaxKind::L_CURLY),
+            make_token_with_l_space(JsSyntaxKind::R_CURLY),
+        )
+    }
+}
+
+/// Indentation of the specifiers wrapped into lines.
+const INDENT: &str = "  ";
+
+/// Makes a token followed by a line break, indenting the next line if `indent` is set.
+fn make_token_with_line_break(kind: JsSyntaxKind, indent: bool) -> JsSyntaxToken {
+    let Some(text) = kind.to_string() else {
+        panic!("token kind {kind:?} cannot be transformed to text")
+    };
+
+    if indent {
+        JsSyntaxToken::new_detached(
+            kind,
+            &format!("{text}\n{INDENT}"),
+            [],
+            [
+                TriviaPiece::newline(1),
+                TriviaPiece::whitespace(INDENT.len() as u32),
+            ],
+        )
+    } else {
+        JsSyntaxToken::new_detached(kind, &format!("{text}\n"), [], [TriviaPiece::newline(1)])
+    }
+}
+
+/// Returns the separators of the list wrapped into lines, ending with a trailing comma.
+fn make_line_separators(len: usize) -> Vec<JsSyntaxToken> {
+    (0..len)
+        .map(|index| make_token_with_line_break(JsSyntaxKind::COMMA, index + 1 < len))
+        .collect()
+}
+
+/// Returns whether the statement is longer than the print width.
+fn exceeds_print_width<N: AstNode<Language = JsLanguage>>(node: &N, config: &Config) -> bool {
+    config
+        .print_width
+        .is_some_and(|width| node.syntax().text_trimmed().to_string().chars().count() > width)
+}
+
+/// Puts each specifier on its own line.
+fn wrap_named_import_specifiers(
+    specifiers: JsNamedImportSpecifiers,
+) -> SyntaxResult<JsNamedImportSpecifiers> {
+    let list = specifiers.specifiers();
+    if list.is_empty() {
+        return Ok(specifiers);
+    }
+
+    Ok(specifiers
+        .with_l_curly_token(make_token_with_line_break(JsSyntaxKind::L_CURLY, true))
+        .with_specifiers(make::js_named_import_specifier_list(
+            list.iter().collect::<Result<Vec<_>, _>>()?,
+            make_line_separators(list.len()),
+        ))
+        .with_r_curly_token(make::token(JsSyntaxKind::R_CURLY)))
+}
+
+/// Puts each named specifier of the import on its own line.
+fn wrap_import(import: JsImport) -> SyntaxResult<JsImport> {
+    let clause = match import.import_clause()? {
+        AnyJsImportClause::JsImportNamedClause(clause) => {
+            let specifiers = wrap_named_import_specifiers(clause.named_specifiers()?)?;
+            clause.with_named_specifiers(specifiers).into()
+        }
+        AnyJsImportClause::JsImportCombinedClause(clause) => match clause.specifier()? {
+            AnyJsCombinedSpecifier::JsNamedImportSpecifiers(specifiers) => {
+                let specifiers = wrap_named_import_specifiers(specifiers)?;
+                clause.with_specifier(specifiers.into()).into()
+            }
+            _ => return Ok(import),
+        },
+        _ => return Ok(import),
+    };
+
+    Ok(import.with_import_clause(clause))
+}
+
+/// Converts the import of empty named specifiers into a side-effect import, or removes them
+/// alongside a default import. Type-only imports are kept, as converting them would add side
+/// effects.
+fn drop_empty_named_specifiers(clause: AnyJsImportClause) -> SyntaxResult<AnyJsImportClause> {
+    Ok(match clause {
+        AnyJsImportClause::JsImportNamedClause(clause)
+            if clause.type_token().is_none()
+                && clause.named_specifiers()?.specifiers().is_empty() =>
+        {
+            let mut builder = make::js_import_bare_clause(clause.source()?);
+
+            if let Some(assertion) = clause.assertion() {
+                builder = builder.with_assertion(assertion);
+            }
+
+            builder.build().into()
+        }
+        AnyJsImportClause::JsImportCombinedClause(clause)
+            if matches!(
+                clause.specifier()?,
+                AnyJsCombinedSpecifier::JsNamedImportSpecifiers(specifiers)
+                    if specifiers.specifiers().is_empty()
+            ) =>
+        {
+            make_default_clause(&clause)?.into()
+        }
+        clause => clause,
+    })
+}
+
+/// Puts each specifier of the re-export on its own line.
+fn wrap_export(export: JsExport) -> SyntaxResult<JsExport> {
+    let AnyJsExportClause::JsExportNamedFromClause(clause) = export.export_clause()? else {
+        return Ok(export);
+    };
+
+    let list = clause.specifiers();
+    if list.is_empty() {
+        return Ok(export);
+    }
+
+    let clause = clause
+        .with_l_curly_token(make_token_with_line_break(JsSyntaxKind::L_CURLY, true))
+        .with_specifiers(make::js_export_named_from_specifier_list(
+            list.iter().collect::<Result<Vec<_>, _>>()?,
+            make_line_separators(list.len()),
+        ))
+        .with_r_curly_token(make::token(JsSyntaxKind::R_CURLY));
+
+    Ok(export.with_export_clause(clause.into()))
+}
+
+/// Returns the string literal with its quotes replaced by the preferred ones. Literals containing
+/// the preferred quote are kept as is, to avoid escaping them.
+fn normalize_quotes(text: &str, style: QuoteStyle) -> String {
+    let quote = match style {
+        QuoteStyle::Preserve => return text.to_string(),
+        QuoteStyle::Single => '\'',
+        QuoteStyle::Double => '"',
+    };
+
+    let Some(inner) = text
+        .strip_prefix(['\'', '"'])
+        .and_then(|text| text.strip_suffix(['\'', '"']))
+    else {
+        return text.to_string();
+    };
+
+    if inner.contains(quote) {
+        text.to_string()
+    } else {
+        format!("{quote}{inner}{quote}")
+    }
+}
+
+fn make_string_literal(token: &JsSyntaxToken, config: &Config) -> JsSyntaxToken {
+    if token.kind() == JsSyntaxKind::JS_STRING_LITERAL {
+        make::ident(&normalize_quotes(token.text_trimmed(), config.quote_style))
+    } else {
+        make::ident(token.text_trimmed())
+    }
+}
+
+/// Returns whether the remade statement should end with a semicolon.
+fn has_semicolon(semicolon: Option<JsSyntaxToken>, config: &Config) -> bool {
+    match config.semicolons {
+        Semicolons::Preserve => semicolon.is_some(),
+        Semicolons::Always => true,
+        Semicolons::Never => false,
+    }
+}
+
+/// Returns whether the statement would continue the previous one without a semicolon between them,
+/// e.g. `(function () {})()`.
+pub fn is_asi_hazard(item: &AnyJsModuleItem) -> bool {
+    item.syntax().first_token().is_some_and(|token| {
+        matches!(
+            token.kind(),
+            JsSyntaxKind::L_PAREN
+                | JsSyntaxKind::L_BRACK
+                | JsSyntaxKind::BACKTICK
+                | JsSyntaxKind::PLUS
+                | JsSyntaxKind::MINUS
+                | JsSyntaxKind::SLASH
+                | JsSyntaxKind::JS_REGEX_LITERAL
+        )
+    })
+}
+
+/// Returns whether the item starts on its own line, rather than following the previous one on the
+/// same line.
+pub fn starts_line(item: &AnyJsModuleItem) -> bool {
+    item.syntax().first_token().is_some_and(|token| {
+        token
+            .leading_trivia()
+            .pieces()
+            .any(|piece| piece.is_newline())
+    })
+}
+
+/// Returns the comments leading the token, without the blank lines before the first comment.
+///
+/// The indentation of the lines is removed, including the one of the lines inside the comments, so
+/// that the comments can be indented again wherever they are put.
+fn leading_comments(token: Option<JsSyntaxToken>) -> Vec<SyntaxTriviaPiece<JsLanguage>> {
+    let Some(token) = token else {
+        return Vec::new();
+    };
+
+    let mut trivia = Vec::<(TriviaPieceKind, String)>::new();
+    let mut indent = String::new();
+    let mut line_start = true;
+
+    for piece in token.leading_trivia().pieces() {
+        if piece.is_newline() {
+            indent.clear();
+            line_start = true;
+        } else if piece.is_whitespace() && line_start {
+            indent = piece.text().to_string();
+            continue;
+        } else {
+            line_start = false;
+        }
+
+        if trivia.is_empty() && !piece.kind().is_comment() {
+            continue;
+        }
+
+        let text = if piece.kind().is_comment() && !indent.is_empty() {
+            piece
+                .text()
+                .split('\n')
+                .map(|line| line.strip_prefix(indent.as_str()).unwrap_or(line))
+                .collect::<Vec<_>>()
+                .join("\n")
+        } else {
+            piece.text().to_string()
+        };
+
+        trivia.push((piece.kind(), text));
+    }
+
+    token
+        .with_leading_trivia(trivia.iter().map(|(kind, text)| (*kind, text.as_str())))
+        .leading_trivia()
+        .pieces()
+        .collect()
+}
+
+/// Returns the whitespaces and the line breaks leading the node, before any comments.
+pub fn leading_whitespace(node: &JsSyntaxNode) -> String {
+    let Some(token) = node.first_token() else {
+        return String::new();
+    };
+
+    token
+        .leading_trivia()
+        .pieces()
+        .take_while(|piece| !piece.kind().is_comment())
+        .map(|piece| piece.text().to_string())
+        .collect()
+}
+
+/// Returns the comments trailing the token on the same line, without the whitespaces after them.
+fn trailing_comments(token: Option<JsSyntaxToken>) -> Vec<SyntaxTriviaPiece<JsLanguage>> {
+    let Some(token) = token else {
+        return Vec::new();
+    };
+
+    let pieces = token.trailing_trivia().pieces().collect::<Vec<_>>();
+    let len = pieces
+        .iter()
+        .rposition(|piece| piece.kind().is_comment())
+        .map_or(0, |position| position + 1);
+
+    pieces.into_iter().take(len).collect()
+}
+
+/// Moves the comments attached to the original node onto the remade one.
+fn transfer_comments<N>(original: &N, remade: N) -> N
+where
+    N: AstNode<Language = JsLanguage> + Clone,
+{
+    let syntax = original.syntax();
+
+    remade
+        .clone()
+        .with_leading_trivia_pieces(leading_comments(syntax.first_token()))
+        .and_then(|node| node.with_trailing_trivia_pieces(trailing_comments(syntax.last_token())))
+        .unwrap_or(remade)
+}
+
 pub trait Remake
 where
     Self: Sized,
 {
-    fn remake(&self) -> SyntaxResult<Self>;
+    fn remake(&self, config: &Config) -> SyntaxResult<Self>;
 }
 
 impl Remake for AnyJsBinding {
-    fn remake(&self) -> SyntaxResult<Self> {
+    fn remake(&self, _config: &Config) -> SyntaxResult<Self> {
         Ok(match self {
             AnyJsBinding::JsBogusBinding(_) => todo!(),
             AnyJsBinding::JsIdentifierBinding(binding) => {
@@ -48,26 +361,26 @@ impl Remake for AnyJsBinding {
 }
 
 impl Remake for AnyJsModuleSource {
-    fn remake(&self) -> SyntaxResult<Self> {
+    fn remake(&self, config: &Config) -> SyntaxResult<Self> {
         Ok(match self {
             AnyJsModuleSource::JsMetavariable(source) => {
                 make::js_metavariable(make::ident(source.value_token()?.text_trimmed())).into()
             }
             AnyJsModuleSource::JsModuleSource(source) => {
-                make::js_module_source(make::ident(source.value_token()?.text_trimmed())).into()
+                make::js_module_source(make_string_literal(&source.value_token()?, config)).into()
             }
         })
     }
 }
 
 impl Remake for AnyJsImportAssertionEntry {
-    fn remake(&self) -> SyntaxResult<Self> {
+    fn remake(&self, config: &Config) -> SyntaxResult<Self> {
         match self {
             Self::JsBogusImportAssertionEntry(_) => todo!(),
             Self::JsImportAssertionEntry(entry) => Ok(make::js_import_assertion_entry(
-                make::ident(entry.key()?.text_trimmed()),
+                make_string_literal(&entry.key()?, config),
                 make_token_with_r_space(JsSyntaxKind::COLON),
-                make::ident(entry.value_token()?.text_trimmed()),
+                make_string_literal(&entry.value_token()?, config),
             )
             .into()),
         }
@@ -75,12 +388,12 @@ impl Remake for AnyJsImportAssertionEntry {
 }
 
 impl Remake for JsImportAssertionEntryList {
-    fn remake(&self) -> SyntaxResult<Self> {
+    fn remake(&self, config: &Config) -> SyntaxResult<Self> {
         Ok(make::js_import_assertion_entry_list(
             self.iter()
-                .map(|entry| Ok(entry?.remake()?))
+                .map(|entry| entry?.remake(config))
                 .collect::<Result<Vec<_>, _>>()?,
-            self.separators()
+            (1..self.len())
                 .map(|_| make_token_with_r_space(JsSyntaxKind::COMMA))
                 .collect::<Vec<_>>(),
         ))
@@ -88,22 +401,22 @@ impl Remake for JsImportAssertionEntryList {
 }
 
 impl Remake for JsImportAssertion {
-    fn remake(&self) -> SyntaxResult<Self> {
+    fn remake(&self, config: &Config) -> SyntaxResult<Self> {
         Ok(make::js_import_assertion(
-            make::token(self.assertion_kind()?.kind()),
+            make_token_with_l_space(self.assertion_kind()?.kind()),
             make::token_decorated_with_space(JsSyntaxKind::L_CURLY),
-            self.assertions().remake()?,
-            make::token_decorated_with_space(JsSyntaxKind::R_CURLY),
+            self.assertions().remake(config)?,
+            make_token_with_l_space(JsSyntaxKind::R_CURLY),
         ))
     }
 }
 
 impl Remake for JsImportBareClause {
-    fn remake(&self) -> SyntaxResult<Self> {
-        let mut builder = make::js_import_bare_clause(self.source()?.remake()?);
+    fn remake(&self, config: &Config) -> SyntaxResult<Self> {
+        let mut builder = make::js_import_bare_clause(self.source()?.remake(config)?);
 
         if let Some(assertion) = self.assertion() {
-            builder = builder.with_assertion(assertion.remake()?);
+            builder = builder.with_assertion(assertion.remake(config)?);
         }
 
         Ok(builder.build())
@@ -111,26 +424,26 @@ impl Remake for JsImportBareClause {
 }
 
 impl Remake for AnyJsCombinedSpecifier {
-    fn remake(&self) -> SyntaxResult<Self> {
+    fn remake(&self, config: &Config) -> SyntaxResult<Self> {
         Ok(match self {
-            Self::JsNamedImportSpecifiers(specifiers) => specifiers.remake()?.into(),
-            Self::JsNamespaceImportSpecifier(specifier) => specifier.remake()?.into(),
+            Self::JsNamedImportSpecifiers(specifiers) => specifiers.remake(config)?.into(),
+            Self::JsNamespaceImportSpecifier(specifier) => specifier.remake(config)?.into(),
         })
     }
 }
 
 impl Remake for JsImportCombinedClause {
-    fn remake(&self) -> SyntaxResult<Self> {
+    fn remake(&self, config: &Config) -> SyntaxResult<Self> {
         let mut builder = make::js_import_combined_clause(
-            self.default_specifier()?.remake()?,
+            self.default_specifier()?.remake(config)?,
             make_token_with_r_space(JsSyntaxKind::COMMA),
-            self.specifier()?.remake()?,
+            self.specifier()?.remake(config)?,
             make::token_decorated_with_space(JsSyntaxKind::FROM_KW),
-            self.source()?.remake()?,
+            self.source()?.remake(config)?,
         );
 
         if let Some(assertion) = self.assertion() {
-            builder = builder.with_assertion(assertion.remake()?)
+            builder = builder.with_assertion(assertion.remake(config)?)
         }
 
         Ok(builder.build())
@@ -138,19 +451,19 @@ impl Remake for JsImportCombinedClause {
 }
 
 impl Remake for JsDefaultImportSpecifier {
-    fn remake(&self) -> SyntaxResult<Self> {
+    fn remake(&self, config: &Config) -> SyntaxResult<Self> {
         Ok(make::js_default_import_specifier(
-            self.local_name()?.remake()?,
+            self.local_name()?.remake(config)?,
         ))
     }
 }
 
 impl Remake for JsImportDefaultClause {
-    fn remake(&self) -> SyntaxResult<Self> {
+    fn remake(&self, config: &Config) -> SyntaxResult<Self> {
         let mut builder = make::js_import_default_clause(
-            self.default_specifier()?.remake()?,
+            self.default_specifier()?.remake(config)?,
             make::token_decorated_with_space(JsSyntaxKind::FROM_KW),
-            self.source()?.remake()?,
+            self.source()?.remake(config)?,
         );
 
         if self.type_token().is_some() {
@@ -158,7 +471,7 @@ impl Remake for JsImportDefaultClause {
         }
 
         if let Some(assertion) = self.assertion() {
-            builder = builder.with_assertion(assertion.remake()?);
+            builder = builder.with_assertion(assertion.remake(config)?);
         }
 
         Ok(builder.build())
@@ -166,11 +479,11 @@ impl Remake for JsImportDefaultClause {
 }
 
 impl Remake for JsNamedImportSpecifier {
-    fn remake(&self) -> SyntaxResult<Self> {
+    fn remake(&self, config: &Config) -> SyntaxResult<Self> {
         let mut builder = make::js_named_import_specifier(
             make::js_literal_export_name(make::ident(self.name()?.value()?.text_trimmed())),
             make::token_decorated_with_space(JsSyntaxKind::AS_KW),
-            self.local_name()?.remake()?,
+            self.local_name()?.remake(config)?,
         );
 
         if self.type_token().is_some() {
@@ -182,8 +495,9 @@ impl Remake for JsNamedImportSpecifier {
 }
 
 impl Remake for JsShorthandNamedImportSpecifier {
-    fn remake(&self) -> SyntaxResult<Self> {
-        let mut builder = make::js_shorthand_named_import_specifier(self.local_name()?.remake()?);
+    fn remake(&self, config: &Config) -> SyntaxResult<Self> {
+        let mut builder =
+            make::js_shorthand_named_import_specifier(self.local_name()?.remake(config)?);
 
         if self.type_token().is_some() {
             builder = builder.with_type_token(make_token_with_r_space(JsSyntaxKind::TYPE_KW));
@@ -194,28 +508,36 @@ impl Remake for JsShorthandNamedImportSpecifier {
 }
 
 impl Remake for AnyJsNamedImportSpecifier {
-    fn remake(&self) -> SyntaxResult<Self> {
+    fn remake(&self, config: &Config) -> SyntaxResult<Self> {
         match self {
             Self::JsBogusNamedImportSpecifier(_) => todo!(),
-            Self::JsNamedImportSpecifier(specifier) => Ok(specifier.remake()?.into()),
-            Self::JsShorthandNamedImportSpecifier(specifier) => Ok(specifier.remake()?.into()),
+            Self::JsNamedImportSpecifier(specifier) => Ok(specifier.remake(config)?.into()),
+            Self::JsShorthandNamedImportSpecifier(specifier) => {
+                Ok(specifier.remake(config)?.into())
+            }
         }
     }
 }
 
 impl Remake for JsNamedImportSpecifierList {
-    fn remake(&self) -> SyntaxResult<Self> {
+    fn remake(&self, config: &Config) -> SyntaxResult<Self> {
         Ok(make::js_named_import_specifier_list(
             {
                 let mut items = self
                     .iter()
-                    .map(|specifier| specifier?.remake())
+                    .map(|specifier| specifier?.remake(config))
                     .collect::<Result<Vec<_>, _>>()?;
 
-                items.sort_by_key(|item| item.imported_name().unwrap().text_trimmed().to_string());
+                let key = |item: &AnyJsNamedImportSpecifier| SortKey {
+                    name: item.imported_name().unwrap().text_trimmed().to_string(),
+                    len: item.syntax().text_trimmed().len().into(),
+                    type_only: item.type_token().is_some(),
+                };
+
+                items.sort_by(|a, b| config.compare(&key(a), &key(b)));
                 items
             },
-            self.separators()
+            (1..self.len())
                 .map(|_| make_token_with_r_space(JsSyntaxKind::COMMA))
                 .collect::<Vec<_>>(),
         ))
@@ -223,47 +545,53 @@ impl Remake for JsNamedImportSpecifierList {
 }
 
 impl Remake for JsNamedImportSpecifiers {
-    fn remake(&self) -> SyntaxResult<Self> {
+    fn remake(&self, config: &Config) -> SyntaxResult<Self> {
+        let (l_curly, r_curly) = make_curly_tokens(self.specifiers().is_empty());
+
         Ok(make::js_named_import_specifiers(
-            make_token_with_r_space(JsSyntaxKind::L_CURLY),
-            self.specifiers().remake()?,
-            make_token_with_l_space(JsSyntaxKind::R_CURLY),
+            l_curly,
+            self.specifiers().remake(config)?,
+            r_curly,
         ))
     }
 }
 
 impl Remake for JsImportNamedClause {
-    fn remake(&self) -> SyntaxResult<Self> {
+    fn remake(&self, config: &Config) -> SyntaxResult<Self> {
         let mut builder = make::js_import_named_clause(
-            self.named_specifiers()?.remake()?,
+            self.named_specifiers()?.remake(config)?,
             make::token_decorated_with_space(JsSyntaxKind::FROM_KW),
-            self.source()?.remake()?,
+            self.source()?.remake(config)?,
         );
 
         if self.type_token().is_some() {
             builder = builder.with_type_token(make_token_with_r_space(JsSyntaxKind::TYPE_KW));
         }
 
+        if let Some(assertion) = self.assertion() {
+            builder = builder.with_assertion(assertion.remake(config)?);
+        }
+
         Ok(builder.build())
     }
 }
 
 impl Remake for JsNamespaceImportSpecifier {
-    fn remake(&self) -> SyntaxResult<Self> {
+    fn remake(&self, config: &Config) -> SyntaxResult<Self> {
         Ok(make::js_namespace_import_specifier(
             make::token(JsSyntaxKind::STAR),
             make::token_decorated_with_space(JsSyntaxKind::AS_KW),
-            self.local_name()?.remake()?,
+            self.local_name()?.remake(config)?,
         ))
     }
 }
 
 impl Remake for JsImportNamespaceClause {
-    fn remake(&self) -> SyntaxResult<Self> {
+    fn remake(&self, config: &Config) -> SyntaxResult<Self> {
         let mut builder = make::js_import_namespace_clause(
-            self.namespace_specifier()?.remake()?,
+            self.namespace_specifier()?.remake(config)?,
             make::token_decorated_with_space(JsSyntaxKind::FROM_KW),
-            self.source()?.remake()?,
+            self.source()?.remake(config)?,
         );
 
         if self.type_token().is_some() {
@@ -271,7 +599,7 @@ impl Remake for JsImportNamespaceClause {
         }
 
         if let Some(assertion) = self.assertion() {
-            builder = builder.with_assertion(assertion.remake()?);
+            builder = builder.with_assertion(assertion.remake(config)?);
         }
 
         Ok(builder.build())
@@ -279,21 +607,465 @@ impl Remake for JsImportNamespaceClause {
 }
 
 impl Remake for JsImport {
-    fn remake(&self) -> SyntaxResult<Self> {
+    fn remake(&self, config: &Config) -> SyntaxResult<Self> {
         let clause = match self.import_clause()? {
-            AnyJsImportClause::JsImportBareClause(clause) => clause.remake()?.into(),
-            AnyJsImportClause::JsImportCombinedClause(clause) => clause.remake()?.into(),
-            AnyJsImportClause::JsImportDefaultClause(clause) => clause.remake()?.into(),
-            AnyJsImportClause::JsImportNamedClause(clause) => clause.remake()?.into(),
-            AnyJsImportClause::JsImportNamespaceClause(clause) => clause.remake()?.into(),
+            AnyJsImportClause::JsImportBareClause(clause) => clause.remake(config)?.into(),
+            AnyJsImportClause::JsImportCombinedClause(clause) => clause.remake(config)?.into(),
+            AnyJsImportClause::JsImportDefaultClause(clause) => clause.remake(config)?.into(),
+            AnyJsImportClause::JsImportNamedClause(clause) => clause.remake(config)?.into(),
+            AnyJsImportClause::JsImportNamespaceClause(clause) => clause.remake(config)?.into(),
+        };
+
+        let clause = if config.drop_empty_named_imports {
+            drop_empty_named_specifiers(clause)?
+        } else {
+            clause
         };
 
         let mut builder = make::js_import(make_token_with_r_space(JsSyntaxKind::IMPORT_KW), clause);
 
-        if self.semicolon_token().is_some() {
+        if has_semicolon(self.semicolon_token(), config) {
+            builder = builder.with_semicolon_token(make::token(JsSyntaxKind::SEMICOLON));
+        }
+
+        let mut import = builder.build();
+        if exceeds_print_width(&import, config) {
+            import = wrap_import(import)?;
+        }
+
+        Ok(transfer_comments(self, import))
+    }
+}
+
+impl Remake for TsExternalModuleReference {
+    fn remake(&self, config: &Config) -> SyntaxResult<Self> {
+        Ok(make::ts_external_module_reference(
+            make::token(JsSyntaxKind::REQUIRE_KW),
+            make::token(JsSyntaxKind::L_PAREN),
+            self.source()?.remake(config)?,
+            make::token(JsSyntaxKind::R_PAREN),
+        ))
+    }
+}
+
+impl Remake for TsImportEqualsDeclaration {
+    fn remake(&self, config: &Config) -> SyntaxResult<Self> {
+        let AnyTsModuleReference::TsExternalModuleReference(reference) = self.module_reference()?
+        else {
+            return Ok(self.clone());
+        };
+
+        let mut builder = make::ts_import_equals_declaration(
+            make_token_with_r_space(JsSyntaxKind::IMPORT_KW),
+            self.id()?.remake(config)?,
+            make::token_decorated_with_space(JsSyntaxKind::EQ),
+            reference.remake(config)?.into(),
+        );
+
+        if self.type_token().is_some() {
+            builder = builder.with_type_token(make_token_with_r_space(JsSyntaxKind::TYPE_KW));
+        }
+
+        if has_semicolon(self.semicolon_token(), config) {
+            builder = builder.with_semicolon_token(make::token(JsSyntaxKind::SEMICOLON));
+        }
+
+        Ok(transfer_comments(self, builder.build()))
+    }
+}
+
+impl Remake for AnyImport {
+    fn remake(&self, config: &Config) -> SyntaxResult<Self> {
+        Ok(match self {
+            Self::JsImport(import) => import.remake(config)?.into(),
+            Self::TsImportEqualsDeclaration(import) => import.remake(config)?.into(),
+        })
+    }
+}
+
+impl Remake for JsLiteralExportName {
+    fn remake(&self, _config: &Config) -> SyntaxResult<Self> {
+        Ok(make::js_literal_export_name(make::ident(
+            self.value()?.text_trimmed(),
+        )))
+    }
+}
+
+impl Remake for JsExportAsClause {
+    fn remake(&self, config: &Config) -> SyntaxResult<Self> {
+        Ok(make::js_export_as_clause(
+            make::token_decorated_with_space(JsSyntaxKind::AS_KW),
+            self.exported_name()?.remake(config)?,
+        ))
+    }
+}
+
+impl Remake for JsExportFromClause {
+    fn remake(&self, config: &Config) -> SyntaxResult<Self> {
+        let mut builder = make::js_export_from_clause(
+            make::token(JsSyntaxKind::STAR),
+            make::token_decorated_with_space(JsSyntaxKind::FROM_KW),
+            self.source()?.remake(config)?,
+        );
+
+        if self.type_token().is_some() {
+            builder = builder.with_type_token(make_token_with_r_space(JsSyntaxKind::TYPE_KW));
+        }
+
+        if let Some(export_as) = self.export_as() {
+            builder = builder.with_export_as(export_as.remake(config)?);
+        }
+
+        if let Some(assertion) = self.assertion() {
+            builder = builder.with_assertion(assertion.remake(config)?);
+        }
+
+        if has_semicolon(self.semicolon_token(), config) {
+            builder = builder.with_semicolon_token(make::token(JsSyntaxKind::SEMICOLON));
+        }
+
+        Ok(builder.build())
+    }
+}
+
+impl Remake for JsExportNamedFromSpecifier {
+    fn remake(&self, config: &Config) -> SyntaxResult<Self> {
+        let mut builder = make::js_export_named_from_specifier(self.source_name()?.remake(config)?);
+
+        if self.type_token().is_some() {
+            builder = builder.with_type_token(make_token_with_r_space(JsSyntaxKind::TYPE_KW));
+        }
+
+        if let Some(export_as) = self.export_as() {
+            builder = builder.with_export_as(export_as.remake(config)?);
+        }
+
+        Ok(builder.build())
+    }
+}
+
+impl Remake for JsExportNamedFromSpecifierList {
+    fn remake(&self, config: &Config) -> SyntaxResult<Self> {
+        Ok(make::js_export_named_from_specifier_list(
+            {
+                let mut items = self
+                    .iter()
+                    .map(|specifier| specifier?.remake(config))
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                let key = |item: &JsExportNamedFromSpecifier| SortKey {
+                    name: item
+                        .source_name()
+                        .and_then(|name| name.value())
+                        .map(|value| value.text_trimmed().to_string())
+                        .unwrap_or_default(),
+                    len: item.syntax().text_trimmed().len().into(),
+                    type_only: item.type_token().is_some(),
+                };
+
+                items.sort_by(|a, b| config.compare(&key(a), &key(b)));
+                items
+            },
+            (1..self.len())
+                .map(|_| make_token_with_r_space(JsSyntaxKind::COMMA))
+                .collect::<Vec<_>>(),
+        ))
+    }
+}
+
+impl Remake for JsExportNamedFromClause {
+    fn remake(&self, config: &Config) -> SyntaxResult<Self> {
+        let (l_curly, r_curly) = make_curly_tokens(self.specifiers().is_empty());
+        let mut builder = make::js_export_named_from_clause(
+            l_curly,
+            self.specifiers().remake(config)?,
+            r_curly,
+            make::token_decorated_with_space(JsSyntaxKind::FROM_KW),
+            self.source()?.remake(config)?,
+        );
+
+        if self.type_token().is_some() {
+            builder = builder.with_type_token(make_token_with_r_space(JsSyntaxKind::TYPE_KW));
+        }
+
+        if let Some(assertion) = self.assertion() {
+            builder = builder.with_assertion(assertion.remake(config)?);
+        }
+
+        if has_semicolon(self.semicolon_token(), config) {
             builder = builder.with_semicolon_token(make::token(JsSyntaxKind::SEMICOLON));
         }
 
         Ok(builder.build())
     }
 }
+
+impl Remake for JsExport {
+    fn remake(&self, config: &Config) -> SyntaxResult<Self> {
+        let clause = match self.export_clause()? {
+            AnyJsExportClause::JsExportFromClause(clause) => clause.remake(config)?.into(),
+            AnyJsExportClause::JsExportNamedFromClause(clause) => clause.remake(config)?.into(),
+            _ => return Ok(self.clone()),
+        };
+
+        let mut export = make::js_export(
+            make::js_decorator_list([]),
+            make_token_with_r_space(JsSyntaxKind::EXPORT_KW),
+            clause,
+        );
+
+        if exceeds_print_width(&export, config) {
+            export = wrap_export(export)?;
+        }
+
+        Ok(transfer_comments(self, export))
+    }
+}
+
+/// Keeps the `require` declarations as written, only detaching them from the blank lines around.
+impl Remake for JsVariableStatement {
+    fn remake(&self, _config: &Config) -> SyntaxResult<Self> {
+        Ok(transfer_comments(self, self.clone()))
+    }
+}
+
+/// Merges the named imports into the first one, removing the duplicate specifiers.
+/// Imports other than named imports are returned as is.
+pub fn merge_named_imports(first: &JsImport, second: &JsImport) -> SyntaxResult<JsImport> {
+    let (
+        AnyJsImportClause::JsImportNamedClause(clause),
+        AnyJsImportClause::JsImportNamedClause(other),
+    ) = (first.import_clause()?, second.import_clause()?)
+    else {
+        return Ok(first.clone());
+    };
+
+    let mut specifiers = Vec::<AnyJsNamedImportSpecifier>::new();
+    for specifier in clause
+        .named_specifiers()?
+        .specifiers()
+        .iter()
+        .chain(other.named_specifiers()?.specifiers().iter())
+    {
+        let specifier = specifier?;
+        if !specifiers
+            .iter()
+            .any(|s| s.syntax().text_trimmed() == specifier.syntax().text_trimmed())
+        {
+            specifiers.push(specifier);
+        }
+    }
+
+    let mut builder = make::js_import_named_clause(
+        make_named_import_specifiers(specifiers),
+        clause.from_token()?,
+        clause.source()?,
+    );
+
+    if let Some(type_token) = clause.type_token() {
+        builder = builder.with_type_token(type_token);
+    }
+
+    if let Some(assertion) = clause.assertion() {
+        builder = builder.with_assertion(assertion);
+    }
+
+    let mut builder = make::js_import(first.import_token()?, builder.build().into());
+
+    if let Some(semicolon) = first.semicolon_token() {
+        builder = builder.with_semicolon_token(semicolon);
+    }
+
+    // Keeps the comments attached to both of the imports, the first ones first.
+    let (first, second) = (first.syntax(), second.syntax());
+    let leading = first
+        .first_token()
+        .map(|token| token.leading_trivia().pieces().collect::<Vec<_>>())
+        .unwrap_or_default()
+        .into_iter()
+        .chain(leading_comments(second.first_token()))
+        .collect::<Vec<_>>();
+    let trailing = trailing_comments(first.last_token())
+        .into_iter()
+        .chain(trailing_comments(second.last_token()))
+        .collect::<Vec<_>>();
+
+    let import = builder.build();
+
+    Ok(import
+        .clone()
+        .with_leading_trivia_pieces(leading)
+        .and_then(|import| import.with_trailing_trivia_pieces(trailing))
+        .unwrap_or(import))
+}
+
+/// Makes the list of the specifiers, separated by commas.
+pub fn make_named_import_specifier_list(
+    specifiers: Vec<AnyJsNamedImportSpecifier>,
+) -> JsNamedImportSpecifierList {
+    let separators = (1..specifiers.len())
+        .map(|_| make::token(JsSyntaxKind::COMMA))
+        .collect::<Vec<_>>();
+
+    make::js_named_import_specifier_list(specifiers, separators)
+}
+
+fn make_named_import_specifiers(
+    specifiers: Vec<AnyJsNamedImportSpecifier>,
+) -> JsNamedImportSpecifiers {
+    make::js_named_import_specifiers(
+        make::token(JsSyntaxKind::L_CURLY),
+        make_named_import_specifier_list(specifiers),
+        make::token(JsSyntaxKind::R_CURLY),
+    )
+}
+
+/// Makes the default import clause out of the combined one, dropping the named or the namespace
+/// specifier, e.g. `a from 'a'` for `a, { b } from 'a'`.
+pub fn make_default_clause(clause: &JsImportCombinedClause) -> SyntaxResult<JsImportDefaultClause> {
+    let mut builder = make::js_import_default_clause(
+        clause.default_specifier()?,
+        clause.from_token()?,
+        clause.source()?,
+    );
+
+    if let Some(assertion) = clause.assertion() {
+        builder = builder.with_assertion(assertion);
+    }
+
+    Ok(builder.build())
+}
+
+/// Makes the named or the namespace import clause out of the combined one, dropping the default
+/// specifier, e.g. `{ b } from 'a'` for `a, { b } from 'a'`.
+pub fn make_clause_without_default(
+    clause: &JsImportCombinedClause,
+) -> SyntaxResult<AnyJsImportClause> {
+    let (from_token, source, assertion) =
+        (clause.from_token()?, clause.source()?, clause.assertion());
+
+    Ok(match clause.specifier()? {
+        AnyJsCombinedSpecifier::JsNamedImportSpecifiers(specifiers) => {
+            let mut builder = make::js_import_named_clause(specifiers, from_token, source);
+            if let Some(assertion) = assertion {
+                builder = builder.with_assertion(assertion);
+            }
+
+            builder.build().into()
+        }
+        AnyJsCombinedSpecifier::JsNamespaceImportSpecifier(specifier) => {
+            let mut builder = make::js_import_namespace_clause(specifier, from_token, source);
+            if let Some(assertion) = assertion {
+                builder = builder.with_assertion(assertion);
+            }
+
+            builder.build().into()
+        }
+    })
+}
+
+/// Returns the node without the leading and the trailing trivia, including the comments.
+fn without_trivia<N>(node: N) -> N
+where
+    N: AstNode<Language = JsLanguage> + Clone,
+{
+    node.clone()
+        .with_leading_trivia_pieces([])
+        .and_then(|node| node.with_trailing_trivia_pieces([]))
+        .unwrap_or(node)
+}
+
+/// Makes a type-only import of the specifiers, e.g. `import type { A } from 'a'`.
+fn make_type_import(
+    import: &JsImport,
+    specifiers: Vec<AnyJsNamedImportSpecifier>,
+    source: AnyJsModuleSource,
+    assertion: Option<JsImportAssertion>,
+) -> JsImport {
+    // Leaves the comments trailing the source or the assertion to the original import.
+    let mut builder = make::js_import_named_clause(
+        make_named_import_specifiers(specifiers),
+        make::token(JsSyntaxKind::FROM_KW),
+        without_trivia(source),
+    )
+    .with_type_token(make::token(JsSyntaxKind::TYPE_KW));
+
+    if let Some(assertion) = assertion {
+        builder = builder.with_assertion(without_trivia(assertion));
+    }
+
+    let mut builder = make::js_import(make::token(JsSyntaxKind::IMPORT_KW), builder.build().into());
+
+    if import.semicolon_token().is_some() {
+        builder = builder.with_semicolon_token(make::token(JsSyntaxKind::SEMICOLON));
+    }
+
+    builder.build()
+}
+
+/// Moves the inline `type` specifiers into a separate `import type` statement, e.g.
+/// `import { a, type B } from 'x'` into `import { a } from 'x'` and `import type { B } from 'x'`.
+/// Imports whose specifiers are all type-only are turned into `import type` as a whole.
+pub fn separate_type_specifiers(import: JsImport) -> SyntaxResult<Vec<JsImport>> {
+    let (specifiers, default_specifier) = match import.import_clause()? {
+        AnyJsImportClause::JsImportNamedClause(clause) if clause.type_token().is_none() => {
+            (clause.named_specifiers()?, None)
+        }
+        AnyJsImportClause::JsImportCombinedClause(clause) => match clause.specifier()? {
+            AnyJsCombinedSpecifier::JsNamedImportSpecifiers(specifiers) => {
+                (specifiers, Some(clause.default_specifier()?))
+            }
+            _ => return Ok(vec![import]),
+        },
+        _ => return Ok(vec![import]),
+    };
+
+    let (types, values): (Vec<_>, Vec<_>) = specifiers
+        .specifiers()
+        .iter()
+        .collect::<Result<Vec<_>, _>>()?
+        .into_iter()
+        .partition(|specifier| specifier.type_token().is_some());
+
+    if types.is_empty() {
+        return Ok(vec![import]);
+    }
+
+    let types = types
+        .into_iter()
+        .map(|specifier| specifier.with_type_token(None))
+        .collect::<Vec<_>>();
+
+    let clause = import.import_clause()?;
+    let source = clause.source()?;
+    let assertion = clause.assertion();
+
+    // Turns the import as a whole, keeping its comments.
+    if values.is_empty() && default_specifier.is_none() {
+        let AnyJsImportClause::JsImportNamedClause(clause) = clause else {
+            return Ok(vec![import]);
+        };
+
+        let clause = clause
+            .with_named_specifiers(make_named_import_specifiers(types))
+            .with_type_token(Some(make::token(JsSyntaxKind::TYPE_KW)));
+
+        return Ok(vec![import.with_import_clause(clause.into())]);
+    }
+
+    let type_import = make_type_import(&import, types, source, assertion);
+    let clause: AnyJsImportClause = match clause {
+        AnyJsImportClause::JsImportCombinedClause(clause) if values.is_empty() => {
+            make_default_clause(&clause)?.into()
+        }
+        AnyJsImportClause::JsImportCombinedClause(clause) => clause
+            .with_specifier(make_named_import_specifiers(values).into())
+            .into(),
+        AnyJsImportClause::JsImportNamedClause(clause) => clause
+            .with_named_specifiers(make_named_import_specifiers(values))
+            .into(),
+        _ => return Ok(vec![import]),
+    };
+
+    Ok(vec![import.with_import_clause(clause), type_import])
+}
@@ -0,0 +1,177 @@
+use std::collections::HashMap;
+use std::fs::read_to_string;
+use std::path::{Path, PathBuf};
+
+use serde::de::IgnoredAny;
+use serde::Deserialize;
+
+use crate::config::ConfigError;
+
+const TSCONFIG_FILE_NAMES: [&str; 2] = ["tsconfig.json", "jsconfig.json"];
+
+const EXTENSIONS: [&str; 10] = [
+    "ts", "tsx", "d.ts", "mts", "cts", "js", "jsx", "mjs", "cjs", "json",
+];
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum RawExtends {
+    One(String),
+    Many(Vec<String>),
+}
+
+#[derive(Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+struct RawCompilerOptions {
+    base_url: Option<String>,
+    paths: Option<HashMap<String, IgnoredAny>>,
+    jsx_factory: Option<String>,
+    jsx_fragment_factory: Option<String>,
+}
+
+#[derive(Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+struct RawTsConfig {
+    extends: Option<RawExtends>,
+    compiler_options: RawCompilerOptions,
+}
+
+/// Resolves the path of a configuration in `extends`, relative to the directory of the extending
+/// configuration or from `node_modules`.
+fn resolve_extends(dir: &Path, name: &str) -> Option<PathBuf> {
+    let with_json = |path: PathBuf| match path.extension() {
+        Some(ext) if ext == "json" => path,
+        _ => PathBuf::from(format!("{}.json", path.display())),
+    };
+
+    if name.starts_with("./") || name.starts_with("../") || Path::new(name).is_absolute() {
+        return Some(with_json(dir.join(name))).filter(|path| path.is_file());
+    }
+
+    dir.ancestors()
+        .map(|dir| dir.join("node_modules").join(name))
+        .flat_map(|path| [with_json(path.clone()), path.join("tsconfig.json")])
+        .find(|path| path.is_file())
+}
+
+/// Returns whether the module specifier matches the key of `compilerOptions.paths`, which may
+/// contain a wildcard.
+fn matches_path_key(key: &str, source: &str) -> bool {
+    match key.split_once('*') {
+        // `*` alone is a fallback for every module and does not mean they are internal.
+        Some(("", "")) => false,
+        Some((prefix, suffix)) => {
+            source.len() >= prefix.len() + suffix.len()
+                && source.starts_with(prefix)
+                && source.ends_with(suffix)
+        }
+        None => key == source,
+    }
+}
+
+/// Subset of `tsconfig.json` or `jsconfig.json` that affects the module resolution and the
+/// bindings referenced by JSX.
+#[derive(Clone, Debug, Default)]
+pub struct TsConfig {
+    base_url: Option<PathBuf>,
+    paths: Option<Vec<String>>,
+    jsx_factory: Option<String>,
+    jsx_fragment_factory: Option<String>,
+}
+
+impl TsConfig {
+    /// Loads the configuration from the file, following the `extends` chain.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, ConfigError> {
+        Self::load_inner(path.as_ref(), &mut Vec::new())
+    }
+
+    fn load_inner(path: &Path, visited: &mut Vec<PathBuf>) -> Result<Self, ConfigError> {
+        if visited.iter().any(|p| p == path) {
+            return Ok(Self::default());
+        }
+        visited.push(path.to_path_buf());
+
+        let text = read_to_string(path).map_err(|e| ConfigError::Io(path.to_path_buf(), e))?;
+        let raw = json5::from_str::<RawTsConfig>(&text)
+            .map_err(|e| ConfigError::Json5(path.to_path_buf(), e))?;
+
+        let dir = path.parent().unwrap_or(Path::new("."));
+        let extends = match raw.extends {
+            Some(RawExtends::One(name)) => vec![name],
+            Some(RawExtends::Many(names)) => names,
+            None => Vec::new(),
+        };
+
+        let mut config = Self::default();
+        for name in extends {
+            if let Some(path) = resolve_extends(dir, &name) {
+                config = config.merge(Self::load_inner(&path, visited)?);
+            }
+        }
+
+        Ok(config.merge(Self {
+            base_url: raw.compiler_options.base_url.map(|url| dir.join(url)),
+            paths: raw
+                .compiler_options
+                .paths
+                .map(|paths| paths.into_keys().collect()),
+            jsx_factory: raw.compiler_options.jsx_factory,
+            jsx_fragment_factory: raw.compiler_options.jsx_fragment_factory,
+        }))
+    }
+
+    /// Finds the nearest `tsconfig.json` or `jsconfig.json` by walking up from the directory.
+    pub fn discover(dir: impl AsRef<Path>) -> Result<Option<Self>, ConfigError> {
+        for dir in dir.as_ref().ancestors() {
+            for name in TSCONFIG_FILE_NAMES {
+                let path = dir.join(name);
+                if path.is_file() {
+                    return Self::load(path).map(Some);
+                }
+            }
+        }
+
+        Ok(None)
+    }
+
+    fn merge(self, other: Self) -> Self {
+        Self {
+            base_url: other.base_url.or(self.base_url),
+            paths: other.paths.or(self.paths),
+            jsx_factory: other.jsx_factory.or(self.jsx_factory),
+            jsx_fragment_factory: other.jsx_fragment_factory.or(self.jsx_fragment_factory),
+        }
+    }
+
+    /// Returns `compilerOptions.jsxFactory`, e.g. `h` or `React.createElement`.
+    pub fn jsx_factory(&self) -> Option<&str> {
+        self.jsx_factory.as_deref()
+    }
+
+    /// Returns `compilerOptions.jsxFragmentFactory`, e.g. `Fragment` or `React.Fragment`.
+    pub fn jsx_fragment_factory(&self) -> Option<&str> {
+        self.jsx_fragment_factory.as_deref()
+    }
+
+    /// Returns whether the module specifier is an alias in `paths` or resolves under `baseUrl`.
+    pub fn is_internal(&self, source: &str) -> bool {
+        if let Some(paths) = &self.paths {
+            if paths.iter().any(|key| matches_path_key(key, source)) {
+                return true;
+            }
+        }
+
+        let Some(base_url) = &self.base_url else {
+            return false;
+        };
+
+        let Some(first) = source.split('/').next() else {
+            return false;
+        };
+
+        base_url.join(first).is_dir()
+            || EXTENSIONS
+                .iter()
+                .any(|ext| base_url.join(format!("{source}.{ext}")).is_file())
+    }
+}
@@ -0,0 +1,196 @@
+use biome_js_factory::make;
+use biome_js_semantic::{semantic_model, SemanticModel, SemanticModelOptions};
+use biome_js_syntax::{
+    AnyJsBinding, AnyJsCombinedSpecifier, AnyJsImportClause, AnyJsRoot, JsExport, JsImport,
+    JsModule, JsNamedImportSpecifiers, JsSyntaxKind, JsSyntaxNode,
+};
+use biome_rowan::{
+    AstNode, AstNodeList, AstSeparatedList, BatchMutationExt, SyntaxError, SyntaxResult,
+};
+
+use crate::analyze::collect_imports;
+use crate::transform::{make_named_import_specifier_list, separate_type_specifiers};
+
+/// Returns whether the reference is in a type position, e.g. `let a: A` or `type B = typeof b`.
+fn is_type_reference(node: &JsSyntaxNode) -> bool {
+    matches!(
+        node.ancestors()
+            .skip(1)
+            .find(|node| node.kind() != JsSyntaxKind::TS_QUALIFIED_NAME)
+            .map(|node| node.kind()),
+        Some(
+            JsSyntaxKind::TS_REFERENCE_TYPE
+                | JsSyntaxKind::TS_NAME_WITH_TYPE_ARGUMENTS
+                | JsSyntaxKind::TS_TYPEOF_TYPE
+        )
+    )
+}
+
+/// Returns whether the node is a class.
+fn is_class(node: &JsSyntaxNode) -> bool {
+    matches!(
+        node.kind(),
+        JsSyntaxKind::JS_CLASS_DECLARATION
+            | JsSyntaxKind::JS_CLASS_EXPRESSION
+            | JsSyntaxKind::JS_CLASS_EXPORT_DEFAULT_DECLARATION
+    )
+}
+
+/// Returns whether the class, one of its members or one of their parameters is decorated.
+fn is_decorated(class: &JsSyntaxNode) -> bool {
+    class
+        .parent()
+        .and_then(JsExport::cast)
+        .is_some_and(|export| !export.decorators().is_empty())
+        || class.descendants().any(|node| {
+            node.kind() == JsSyntaxKind::JS_DECORATOR
+                && node.ancestors().find(is_class).as_ref() == Some(class)
+        })
+}
+
+/// Returns whether the reference is in the signature of a member of a decorated class, e.g. the
+/// type of a constructor parameter, which `emitDecoratorMetadata` emits as a value.
+fn is_decorator_metadata(node: &JsSyntaxNode) -> bool {
+    node.ancestors()
+        .take_while(|node| node.kind() != JsSyntaxKind::JS_FUNCTION_BODY)
+        .find(|node| node.kind() == JsSyntaxKind::JS_CLASS_MEMBER_LIST)
+        .and_then(|members| members.parent())
+        .is_some_and(|class| is_decorated(&class))
+}
+
+/// Returns whether the binding is referenced, and only in type positions. The types in the
+/// signatures of decorated classes are not, as they may be emitted as values.
+fn is_type_only(model: &SemanticModel, binding: SyntaxResult<AnyJsBinding>) -> bool {
+    let Ok(AnyJsBinding::JsIdentifierBinding(binding)) = binding else {
+        return false;
+    };
+
+    let mut references = model.as_binding(&binding).all_references().peekable();
+
+    references.peek().is_some()
+        && references.all(|reference| {
+            is_type_reference(reference.syntax()) && !is_decorator_metadata(reference.syntax())
+        })
+}
+
+/// Adds the `type` modifier to the specifiers used only as types, or returns `None` if there are
+/// no such specifiers.
+fn mark_type_specifiers(
+    model: &SemanticModel,
+    specifiers: &JsNamedImportSpecifiers,
+) -> SyntaxResult<Option<JsNamedImportSpecifiers>> {
+    let list = specifiers.specifiers();
+    let mut changed = false;
+
+    let items = list
+        .iter()
+        .map(|specifier| {
+            let specifier = specifier?;
+            if specifier.type_token().is_some()
+                || !is_type_only(
+                    model,
+                    specifier
+                        .local_name()
+                        .ok_or(SyntaxError::MissingRequiredChild),
+                )
+            {
+                return Ok(specifier);
+            }
+
+            changed = true;
+            Ok(specifier.with_type_token(Some(make::token(JsSyntaxKind::TYPE_KW))))
+        })
+        .collect::<SyntaxResult<Vec<_>>>()?;
+
+    if !changed {
+        return Ok(None);
+    }
+
+    Ok(Some(
+        specifiers
+            .clone()
+            .with_specifiers(make_named_import_specifier_list(items)),
+    ))
+}
+
+/// Converts the import into a type-only one as far as its bindings are used only as types, or
+/// returns `None` if there is nothing to convert.
+fn convert_import(model: &SemanticModel, import: &JsImport) -> SyntaxResult<Option<JsImport>> {
+    let clause: AnyJsImportClause = match import.import_clause()? {
+        AnyJsImportClause::JsImportDefaultClause(clause)
+            if clause.type_token().is_none()
+                && is_type_only(model, clause.default_specifier()?.local_name()) =>
+        {
+            clause
+                .with_type_token(Some(make::token(JsSyntaxKind::TYPE_KW)))
+                .into()
+        }
+        AnyJsImportClause::JsImportNamespaceClause(clause)
+            if clause.type_token().is_none()
+                && is_type_only(model, clause.namespace_specifier()?.local_name()) =>
+        {
+            clause
+                .with_type_token(Some(make::token(JsSyntaxKind::TYPE_KW)))
+                .into()
+        }
+        AnyJsImportClause::JsImportNamedClause(clause) if clause.type_token().is_none() => {
+            let Some(specifiers) = mark_type_specifiers(model, &clause.named_specifiers()?)? else {
+                return Ok(None);
+            };
+
+            clause.with_named_specifiers(specifiers).into()
+        }
+        AnyJsImportClause::JsImportCombinedClause(clause) => {
+            let AnyJsCombinedSpecifier::JsNamedImportSpecifiers(specifiers) = clause.specifier()?
+            else {
+                return Ok(None);
+            };
+
+            let Some(specifiers) = mark_type_specifiers(model, &specifiers)? else {
+                return Ok(None);
+            };
+
+            clause.with_specifier(specifiers.into()).into()
+        }
+        _ => return Ok(None),
+    };
+
+    let import = import.clone().with_import_clause(clause);
+
+    // Named imports whose specifiers are all used only as types are turned into `import type`, so
+    // that they are erased as a whole.
+    let all_types = match import.import_clause()? {
+        AnyJsImportClause::JsImportNamedClause(clause) => clause
+            .named_specifiers()?
+            .specifiers()
+            .iter()
+            .all(|specifier| specifier.is_ok_and(|specifier| specifier.type_token().is_some())),
+        _ => false,
+    };
+
+    if all_types {
+        return Ok(separate_type_specifiers(import)?.into_iter().next());
+    }
+
+    Ok(Some(import))
+}
+
+/// Marks the imported bindings referenced only in type positions as type-only, so that the module
+/// builds with `isolatedModules` and `verbatimModuleSyntax`.
+pub fn convert_type_only_imports(module: JsModule) -> JsModule {
+    let model = semantic_model(
+        &AnyJsRoot::JsModule(module.clone()),
+        SemanticModelOptions::default(),
+    );
+
+    let imports = collect_imports(&module);
+    let mut mutation = module.begin();
+
+    for import in imports {
+        if let Ok(Some(converted)) = convert_import(&model, &import) {
+            mutation.replace_node(import, converted);
+        }
+    }
+
+    JsModule::unwrap_cast(mutation.commit())
+}
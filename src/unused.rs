@@ -0,0 +1,281 @@
+use biome_js_semantic::{semantic_model, SemanticModel, SemanticModelOptions};
+use biome_js_syntax::{
+    AnyJsBinding, AnyJsCombinedSpecifier, AnyJsImportClause, AnyJsRoot, JsImport, JsLanguage,
+    JsModule, JsNamedImportSpecifiers, JsSyntaxKind, JsSyntaxToken,
+};
+use biome_rowan::{
+    AstNode, AstSeparatedList, BatchMutationExt, SyntaxError, SyntaxResult, SyntaxTriviaPiece,
+};
+
+use crate::transform::{
+    make_clause_without_default, make_default_clause, make_named_import_specifier_list,
+};
+use crate::tsconfig::TsConfig;
+
+/// The binding referenced by the classic JSX runtime unless configured otherwise.
+const DEFAULT_JSX_FACTORY: &str = "React";
+
+/// Returns the value of the pragma in the comment, e.g. `h` for `/** @jsx h */`.
+fn find_pragma<'a>(comment: &'a str, pragma: &str) -> Option<&'a str> {
+    comment.match_indices(pragma).find_map(|(index, _)| {
+        // Requires a whitespace after the name, so that `@jsx` doesn't match `@jsxFrag`.
+        let value = comment[index + pragma.len()..]
+            .strip_prefix(char::is_whitespace)?
+            .trim_start();
+        let end = value
+            .find(|c: char| !(c.is_alphanumeric() || matches!(c, '_' | '$' | '.')))
+            .unwrap_or(value.len());
+
+        Some(&value[..end]).filter(|value| !value.is_empty())
+    })
+}
+
+/// The bindings referenced by the compiled JSX, which the semantic model doesn't see the
+/// references to.
+pub struct JsxFactories {
+    element: String,
+    fragment: String,
+}
+
+impl JsxFactories {
+    /// Reads the `@jsx` and `@jsxFrag` pragmas in the comments leading the module, falling back to
+    /// `jsxFactory` and `jsxFragmentFactory` of the configuration, and then to `React`. Only the
+    /// first identifier of the factories is kept, e.g. `React` for `React.createElement`.
+    pub fn read(module: &JsModule, tsconfig: Option<&TsConfig>) -> Self {
+        let pragma = |name: &str| {
+            module
+                .syntax()
+                .first_token()?
+                .leading_trivia()
+                .pieces()
+                .filter(|piece| piece.is_comments())
+                .find_map(|piece| find_pragma(piece.text(), name).map(str::to_string))
+        };
+        let binding = |factory: Option<&str>| {
+            let factory = factory.unwrap_or(DEFAULT_JSX_FACTORY);
+            factory.split('.').next().unwrap_or(factory).to_string()
+        };
+
+        Self {
+            element: binding(
+                pragma("@jsx")
+                    .as_deref()
+                    .or(tsconfig.and_then(TsConfig::jsx_factory)),
+            ),
+            fragment: binding(
+                pragma("@jsxFrag")
+                    .as_deref()
+                    .or(tsconfig.and_then(TsConfig::jsx_fragment_factory)),
+            ),
+        }
+    }
+
+    /// Returns the bindings referenced by the JSX of the module. Fragments are created by the
+    /// element factory as well.
+    fn referenced_in(&self, module: &JsModule) -> Vec<String> {
+        let (mut elements, mut fragments) = (false, false);
+        for node in module.syntax().descendants() {
+            match node.kind() {
+                JsSyntaxKind::JSX_ELEMENT | JsSyntaxKind::JSX_SELF_CLOSING_ELEMENT => {
+                    elements = true
+                }
+                JsSyntaxKind::JSX_FRAGMENT => fragments = true,
+                _ => {}
+            }
+        }
+
+        [
+            (elements || fragments).then(|| self.element.clone()),
+            fragments.then(|| self.fragment.clone()),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+}
+
+struct Usage {
+    model: SemanticModel,
+    jsx: Vec<String>,
+}
+
+impl Usage {
+    /// Returns whether the binding is referenced anywhere in the module, including JSX and types.
+    fn is_used(&self, binding: SyntaxResult<AnyJsBinding>) -> bool {
+        let Ok(AnyJsBinding::JsIdentifierBinding(binding)) = binding else {
+            return true;
+        };
+
+        if binding
+            .name_token()
+            .is_ok_and(|name| self.jsx.iter().any(|jsx| jsx == name.text_trimmed()))
+        {
+            return true;
+        }
+
+        self.model
+            .as_binding(&binding)
+            .all_references()
+            .next()
+            .is_some()
+    }
+
+    /// Returns the named specifiers without the unused ones, or `None` if none of them is used.
+    /// Empty specifiers are returned as is.
+    fn retain_named(
+        &self,
+        specifiers: JsNamedImportSpecifiers,
+    ) -> SyntaxResult<Option<JsNamedImportSpecifiers>> {
+        let list = specifiers.specifiers();
+        if list.is_empty() {
+            return Ok(Some(specifiers));
+        }
+
+        let used = list
+            .iter()
+            .collect::<SyntaxResult<Vec<_>>>()?
+            .into_iter()
+            .filter(|specifier| {
+                self.is_used(
+                    specifier
+                        .local_name()
+                        .ok_or(SyntaxError::MissingRequiredChild),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        if used.is_empty() {
+            return Ok(None);
+        }
+
+        if used.len() == list.len() {
+            return Ok(Some(specifiers));
+        }
+
+        Ok(Some(
+            specifiers.with_specifiers(make_named_import_specifier_list(used)),
+        ))
+    }
+
+    /// Returns the import without the unused specifiers, or `None` if none of them is used.
+    fn retain_import(&self, import: &JsImport) -> SyntaxResult<Option<JsImport>> {
+        let clause = import.import_clause()?;
+        let retained = self.retain(clause.clone())?;
+
+        Ok(retained.map(|retained| {
+            if retained.syntax().text() == clause.syntax().text() {
+                import.clone()
+            } else {
+                import.clone().with_import_clause(retained)
+            }
+        }))
+    }
+
+    /// Returns the import clause without the unused specifiers, or `None` if none of them is used.
+    fn retain(&self, clause: AnyJsImportClause) -> SyntaxResult<Option<AnyJsImportClause>> {
+        Ok(match clause {
+            AnyJsImportClause::JsImportBareClause(_) => Some(clause),
+            AnyJsImportClause::JsImportDefaultClause(ref default) => self
+                .is_used(default.default_specifier()?.local_name())
+                .then_some(clause),
+            AnyJsImportClause::JsImportNamespaceClause(ref namespace) => self
+                .is_used(namespace.namespace_specifier()?.local_name())
+                .then_some(clause),
+            AnyJsImportClause::JsImportNamedClause(named) => self
+                .retain_named(named.named_specifiers()?)?
+                .map(|specifiers| named.with_named_specifiers(specifiers).into()),
+            AnyJsImportClause::JsImportCombinedClause(combined) => {
+                let default = self.is_used(combined.default_specifier()?.local_name());
+                let specifier = match combined.specifier()? {
+                    AnyJsCombinedSpecifier::JsNamedImportSpecifiers(specifiers) => {
+                        self.retain_named(specifiers)?.map(Into::into)
+                    }
+                    AnyJsCombinedSpecifier::JsNamespaceImportSpecifier(specifier) => self
+                        .is_used(specifier.local_name())
+                        .then_some(specifier.into()),
+                };
+
+                match (default, specifier) {
+                    (true, Some(specifier)) => Some(combined.with_specifier(specifier).into()),
+                    (true, None) => Some(make_default_clause(&combined)?.into()),
+                    (false, Some(specifier)) => Some(make_clause_without_default(
+                        &combined.with_specifier(specifier),
+                    )?),
+                    (false, None) => None,
+                }
+            }
+        })
+    }
+}
+
+/// Returns the leading trivia of the token without the line break ending the previous line, which
+/// the comments moved in front of it already end with.
+fn without_first_line_break(token: &JsSyntaxToken) -> Vec<SyntaxTriviaPiece<JsLanguage>> {
+    let mut pieces = token.leading_trivia().pieces().peekable();
+    pieces.next_if(|piece| piece.is_newline());
+    pieces.collect()
+}
+
+/// Removes the imported bindings that are never referenced, and the imports with no bindings left.
+/// Side-effect imports are kept. The comments leading a removed import are moved to the next item.
+pub fn remove_unused_imports(module: JsModule, jsx: &JsxFactories) -> JsModule {
+    let usage = Usage {
+        model: semantic_model(
+            &AnyJsRoot::JsModule(module.clone()),
+            SemanticModelOptions::default(),
+        ),
+        jsx: jsx.referenced_in(&module),
+    };
+
+    let mut mutation = module.clone().begin();
+    let mut comments = Vec::new();
+
+    for item in module.items() {
+        let retained = match item
+            .as_js_import()
+            .map(|import| usage.retain_import(import))
+        {
+            Some(Ok(None)) => {
+                match item.syntax().first_token() {
+                    Some(token) if !comments.is_empty() => {
+                        comments.extend(without_first_line_break(&token))
+                    }
+                    Some(token) if token.has_leading_comments() => {
+                        comments.extend(token.leading_trivia().pieces())
+                    }
+                    _ => {}
+                }
+
+                mutation.remove_node(item);
+                continue;
+            }
+            Some(Ok(Some(import))) => import.into(),
+            _ => item.clone(),
+        };
+
+        let remade = match retained.syntax().first_token() {
+            Some(token) if !comments.is_empty() => {
+                let trivia = comments.drain(..).chain(without_first_line_break(&token));
+                retained
+                    .clone()
+                    .with_leading_trivia_pieces(trivia.collect::<Vec<_>>())
+                    .unwrap_or(retained)
+            }
+            _ => retained,
+        };
+
+        if remade.syntax().text() != item.syntax().text() {
+            mutation.replace_node_discard_trivia(item, remade);
+        }
+    }
+
+    if let Some(eof) = module.eof_token().ok().filter(|_| !comments.is_empty()) {
+        let trivia = comments.into_iter().chain(without_first_line_break(&eof));
+        mutation.replace_token_discard_trivia(
+            eof.clone(),
+            eof.with_leading_trivia_pieces(trivia.collect::<Vec<_>>()),
+        );
+    }
+
+    JsModule::unwrap_cast(mutation.commit())
+}
@@ -1,6 +1,9 @@
 use biome_js_syntax::JsFileSource;
 use insta::assert_snapshot;
-use tsimports::tsimports;
+use tsimports::{
+    is_commonjs, tsimports, tsimports_with_config, Config, QuoteStyle, Resolver, Semicolons,
+    SortOrder, TypeImportStyle, TypeOrder,
+};
 
 #[test]
 fn snapshot_tests() {
@@ -28,3 +31,830 @@ import {} from '#import'
     )
     .unwrap());
 }
+
+#[test]
+fn leading_comments() {
+    assert_snapshot!(tsimports(
+        "\
+import b from 'b'
+// eslint-disable-next-line import/no-default-export
+import a from 'a'
+// @ts-ignore
+// @ts-expect-error
+import c from 'c'
+",
+        JsFileSource::ts(),
+    )
+    .unwrap());
+}
+
+#[test]
+fn trailing_comments() {
+    assert_snapshot!(tsimports(
+        "\
+import b from 'b' // trailing b
+import a from 'a'; // trailing a
+import c from 'c' /* trailing c */
+",
+        JsFileSource::ts(),
+    )
+    .unwrap());
+}
+
+#[test]
+fn block_comments() {
+    assert_snapshot!(tsimports(
+        "\
+import b from 'b'
+/**
+ * Documentation for a.
+ */
+import a from 'a'
+/* inline */ import c from 'c'
+",
+        JsFileSource::ts(),
+    )
+    .unwrap());
+}
+
+#[test]
+fn header() {
+    assert_snapshot!(tsimports(
+        "\
+#!/usr/bin/env node
+/**
+ * Copyright (c) Example, Inc.
+ */
+'use client'
+'use strict'
+
+// @ts-check
+
+import b from 'b'
+// attached to a
+import a from 'a'
+
+console.log(a, b)
+",
+        JsFileSource::ts(),
+    )
+    .unwrap());
+}
+
+#[test]
+fn header_license() {
+    assert_snapshot!(tsimports(
+        "\
+/*! Example v1.0.0 | MIT License */
+/**
+ * @license Apache-2.0
+ */
+import b from 'b'
+// attached to a
+import a from 'a'
+",
+        JsFileSource::ts(),
+    )
+    .unwrap());
+}
+
+#[test]
+fn header_only() {
+    assert_snapshot!(tsimports(
+        "\
+#!/usr/bin/env node
+'use strict'
+// comment
+",
+        JsFileSource::ts(),
+    )
+    .unwrap());
+}
+
+#[test]
+fn custom_groups() {
+    let config = serde_json::from_str::<Config>(
+        r#"{
+            "groups": [
+                { "kind": "builtin" },
+                { "kind": "external" },
+                { "glob": "@company/**" },
+                { "regex": "^~/" },
+                { "kind": "parent" },
+                { "kind": "sibling" }
+            ]
+        }"#,
+    )
+    .unwrap();
+
+    assert_snapshot!(tsimports_with_config(
+        "\
+import bar from './bar'
+import utils from '~/utils'
+import ui from '@company/ui/button'
+import react from 'react'
+import foo from '../foo'
+import fs from 'node:fs'
+import main from '.'
+import api from '@company/api'
+",
+        JsFileSource::ts(),
+        &config,
+        &Resolver::default(),
+    )
+    .unwrap());
+}
+
+#[test]
+fn tsconfig_paths() {
+    let resolver = Resolver::discover("tests/fixtures/tsconfig", &Config::default()).unwrap();
+
+    assert_snapshot!(tsimports_with_config(
+        "\
+import foo from 'src/foo'
+import button from '@app/components/button'
+import react from 'react'
+import utils from '~/utils'
+import { z } from 'zod'
+",
+        JsFileSource::ts(),
+        &Config::default(),
+        &resolver,
+    )
+    .unwrap());
+}
+
+#[test]
+fn tsconfig_jsx_factory() {
+    let config = Config {
+        remove_unused: true,
+        ..Default::default()
+    };
+    let resolver = Resolver::discover("tests/fixtures/tsconfig", &config).unwrap();
+
+    assert_snapshot!(tsimports_with_config(
+        "\
+import { h } from 'preact'
+import React from 'react'
+
+export const App = () => <div />
+",
+        JsFileSource::tsx(),
+        &config,
+        &resolver,
+    )
+    .unwrap());
+}
+
+#[test]
+fn package_dependencies() {
+    let config = Config {
+        resolve_packages: true,
+        ..Default::default()
+    };
+    let resolver = Resolver::discover("tests/fixtures/workspace/apps/web", &config).unwrap();
+
+    assert_snapshot!(tsimports_with_config(
+        "\
+import foo from 'src/foo'
+import { Button } from '@company/ui/button'
+import { useQuery } from '@tanstack/react-query'
+import lodash from 'lodash'
+import react from 'react'
+import { describe } from 'vitest'
+import fs from 'node:fs'
+",
+        JsFileSource::ts(),
+        &config,
+        &resolver,
+    )
+    .unwrap());
+}
+
+#[test]
+fn package_type() {
+    assert!(is_commonjs("tests/fixtures/workspace/apps/web/src").unwrap());
+    assert!(!is_commonjs("tests/fixtures/esm").unwrap());
+}
+
+#[test]
+fn merge_duplicates() {
+    let config = Config {
+        merge_duplicates: true,
+        ..Default::default()
+    };
+
+    assert_snapshot!(tsimports_with_config(
+        "\
+import { b } from 'x'
+import type { C } from 'x'
+import { a, b } from 'x'
+import * as x from 'x'
+import type { D } from 'x'
+import { type E } from 'x'
+import y from 'y'
+",
+        JsFileSource::ts(),
+        &config,
+        &Resolver::default(),
+    )
+    .unwrap());
+
+    assert_snapshot!(
+        "merge_duplicates_comments",
+        tsimports_with_config(
+            "\
+// comment of a
+import { a } from 'x'
+// @ts-ignore
+import { b } from 'x' // why
+",
+            JsFileSource::ts(),
+            &config,
+            &Resolver::default(),
+        )
+        .unwrap()
+    );
+}
+
+#[test]
+fn side_effect_barriers() {
+    let config = Config {
+        side_effect_barriers: true,
+        ..Default::default()
+    };
+
+    assert_snapshot!(tsimports_with_config(
+        "\
+import z from 'z'
+import a from 'a'
+import 'reflect-metadata'
+import './polyfills'
+import { foo } from './foo'
+import path from 'node:path'
+import './styles.css'
+import c from 'c'
+import b from 'b'
+",
+        JsFileSource::ts(),
+        &config,
+        &Resolver::default(),
+    )
+    .unwrap());
+}
+
+#[test]
+fn re_exports() {
+    assert_snapshot!(tsimports(
+        "\
+import { z } from 'zod'
+export { c, type B, a as A } from './c'
+export * from 'react'
+export * as utils from '~/utils'
+export type * from './types'
+export const schema = z.object({})
+",
+        JsFileSource::ts(),
+    )
+    .unwrap());
+}
+
+#[test]
+fn contiguous_blocks() {
+    let config = Config {
+        contiguous_blocks: true,
+        ..Default::default()
+    };
+
+    assert_snapshot!(tsimports_with_config(
+        "\
+// header
+
+import { foo } from './foo'
+import b from 'b'
+import a from 'a'
+
+jest.mock('./foo')
+
+// comment of c
+import c from 'c'
+import path from 'node:path'
+export * from './z'
+export * from './y'
+
+const x = 1
+
+import d from 'd'
+",
+        JsFileSource::ts(),
+        &config,
+        &Resolver::default(),
+    )
+    .unwrap());
+
+    for semicolons in [Semicolons::Preserve, Semicolons::Never] {
+        let config = Config {
+            semicolons,
+            ..config.clone()
+        };
+
+        assert_snapshot!(
+            format!("contiguous_blocks_same_line_{semicolons:?}").to_lowercase(),
+            tsimports_with_config(
+                "import b from 'b'; foo(); import a from 'a'\n",
+                JsFileSource::ts(),
+                &config,
+                &Resolver::default(),
+            )
+            .unwrap()
+        );
+    }
+}
+
+#[test]
+fn quote_style() {
+    let input = "\
+import a from \"a\"
+import { b } from 'b'
+import data from './data.json' with { \"type\": \"json\" }
+import it from \"it's\"
+export * from \"./c\"
+";
+
+    for quote_style in [QuoteStyle::Preserve, QuoteStyle::Single, QuoteStyle::Double] {
+        let config = Config {
+            quote_style,
+            ..Default::default()
+        };
+
+        assert_snapshot!(
+            format!("quote_style_{quote_style:?}").to_lowercase(),
+            tsimports_with_config(input, JsFileSource::ts(), &config, &Resolver::default())
+                .unwrap()
+        );
+    }
+}
+
+#[test]
+fn semicolons() {
+    let input = "\
+import b from 'b';
+import a from 'a' // trailing a
+export * from './c';
+
+[a, b].forEach(console.log)
+";
+
+    for semicolons in [Semicolons::Preserve, Semicolons::Always, Semicolons::Never] {
+        let config = Config {
+            semicolons,
+            ..Default::default()
+        };
+
+        assert_snapshot!(
+            format!("semicolons_{semicolons:?}").to_lowercase(),
+            tsimports_with_config(input, JsFileSource::ts(), &config, &Resolver::default())
+                .unwrap()
+        );
+    }
+}
+
+#[test]
+fn print_width() {
+    let config = Config {
+        print_width: Some(60),
+        ..Default::default()
+    };
+
+    assert_snapshot!(tsimports_with_config(
+        "\
+import { useState } from 'react'
+import React, { useCallback, useEffect, useMemo, useRef, useState } from 'react'; // hooks
+import {
+  b,
+  a,
+} from 'b'
+import type { LongTypeName, AnotherLongTypeName, YetAnotherTypeName } from './types'
+export { first, second, third, fourth, fifth, sixth } from './numbers'
+",
+        JsFileSource::ts(),
+        &config,
+        &Resolver::default(),
+    )
+    .unwrap());
+}
+
+#[test]
+fn drop_empty_named_imports() {
+    let config = Config {
+        drop_empty_named_imports: true,
+        ..Default::default()
+    };
+
+    assert_snapshot!(tsimports_with_config(
+        "\
+import {} from 'a'
+import b, {} from 'b'
+import type {} from 'c'
+import {} from './data.json' with { type: 'json' }
+import { d } from 'd'
+",
+        JsFileSource::ts(),
+        &config,
+        &Resolver::default(),
+    )
+    .unwrap());
+}
+
+#[test]
+fn sort_order() {
+    let input = "\
+import { Zod, file10, axios, file2 } from 'zod'
+import a from 'Zod'
+import b from 'axios'
+import c from './file10'
+import d from './file2'
+import { aVeryLongSpecifierName } from './file1'
+";
+
+    for sort_order in [
+        SortOrder::Ascii,
+        SortOrder::CaseInsensitive,
+        SortOrder::Natural,
+        SortOrder::LineLength,
+    ] {
+        let config = Config {
+            sort_order,
+            ..Default::default()
+        };
+
+        assert_snapshot!(
+            format!("sort_order_{sort_order:?}").to_lowercase(),
+            tsimports_with_config(input, JsFileSource::ts(), &config, &Resolver::default())
+                .unwrap()
+        );
+    }
+}
+
+#[test]
+fn type_order() {
+    let input = "\
+import { type B, a, type A, b } from 'a'
+import type { C } from 'c'
+import c from 'c'
+import type { D } from 'd'
+export { type F, e } from './e'
+";
+
+    for type_order in [TypeOrder::Mixed, TypeOrder::First, TypeOrder::Last] {
+        let config = Config {
+            type_order,
+            ..Default::default()
+        };
+
+        assert_snapshot!(
+            format!("type_order_{type_order:?}").to_lowercase(),
+            tsimports_with_config(input, JsFileSource::ts(), &config, &Resolver::default())
+                .unwrap()
+        );
+    }
+}
+
+#[test]
+fn type_groups() {
+    let input = "\
+import type { Foo } from './foo'
+import { foo } from './foo'
+import type React from 'react'
+import { useState } from 'react'
+import type * as fs from 'node:fs'
+import path from 'node:path'
+";
+
+    let config = serde_json::from_str::<Config>(
+        r#"{
+            "groups": [
+                { "kind": "builtin" },
+                { "kind": "external" },
+                { "kind": "sibling" },
+                "type"
+            ]
+        }"#,
+    )
+    .unwrap();
+
+    assert_snapshot!(
+        "type_groups_dedicated",
+        tsimports_with_config(input, JsFileSource::ts(), &config, &Resolver::default()).unwrap()
+    );
+
+    let config = Config {
+        type_subgroups: true,
+        ..Default::default()
+    };
+
+    assert_snapshot!(
+        "type_groups_subgroups",
+        tsimports_with_config(input, JsFileSource::ts(), &config, &Resolver::default()).unwrap()
+    );
+}
+
+#[test]
+fn type_import_style() {
+    let input = "\
+import { type A, type B } from 'a'
+import { c, type C } from 'c' // trailing c
+import D, { type E } from 'd';
+import type { F, G } from 'f'
+import type H from 'h'
+import { i } from 'i'
+";
+
+    let config = Config {
+        type_import_style: TypeImportStyle::Separate,
+        ..Default::default()
+    };
+
+    assert_snapshot!(
+        "type_import_style_separate",
+        tsimports_with_config(input, JsFileSource::ts(), &config, &Resolver::default()).unwrap()
+    );
+}
+
+#[test]
+fn remove_unused() {
+    let config = Config {
+        remove_unused: true,
+        ..Default::default()
+    };
+
+    assert_snapshot!(tsimports_with_config(
+        "\
+import React, { useState, useEffect } from 'react'
+import type { FC, ReactNode } from 'react'
+import unused, { used } from 'a'
+import def, * as ns from 'b'
+import * as unusedNamespace from 'c'
+import { Button } from './button'
+import './styles.css'
+// comment of d
+import { d, e } from 'd'
+
+export const App: FC = () => {
+  const [state] = useState(used)
+  return <Button>{state}{d}</Button>
+}
+
+export { ns }
+",
+        JsFileSource::tsx(),
+        &config,
+        &Resolver::default(),
+    )
+    .unwrap());
+
+    assert_snapshot!(
+        "remove_unused_header",
+        tsimports_with_config(
+            "\
+// Copyright ACME
+
+import a from 'a'
+import b from 'b'
+
+console.log(b)
+",
+            JsFileSource::ts(),
+            &config,
+            &Resolver::default(),
+        )
+        .unwrap()
+    );
+
+    assert_snapshot!(
+        "remove_unused_jsx_pragma",
+        tsimports_with_config(
+            "\
+/** @jsx h */
+/** @jsxFrag Fragment */
+import { h, Fragment } from 'preact'
+import React from 'react'
+
+export const App = () => <><div /></>
+",
+            JsFileSource::tsx(),
+            &config,
+            &Resolver::default(),
+        )
+        .unwrap()
+    );
+
+    assert_snapshot!(
+        "remove_unused_comments",
+        tsimports_with_config(
+            "\
+import a from 'a'
+// comment of b
+import b from 'b'
+/* comment of c */
+import c from 'c'
+import d from 'd'
+// comment of e
+import e from 'e'
+
+console.log(a, d)
+",
+            JsFileSource::ts(),
+            &config,
+            &Resolver::default(),
+        )
+        .unwrap()
+    );
+}
+
+#[test]
+fn convert_type_only_imports() {
+    let config = Config {
+        convert_type_only_imports: true,
+        ..Default::default()
+    };
+
+    assert_snapshot!(tsimports_with_config(
+        "\
+import { Foo, Bar } from './types'
+import { useState, type Dispatch, SetStateAction } from 'react'
+import Schema from './schema'
+import * as api from './api'
+import config, { Options } from './config'
+import { exported } from './exported'
+
+let foo: Foo
+let bar: Bar.Baz
+type Api = typeof api
+const [state, setState] = useState<Options>(config)
+let dispatch: Dispatch<SetStateAction<Options>>
+function parse(input: unknown): Schema {}
+
+export { exported }
+",
+        JsFileSource::ts(),
+        &config,
+        &Resolver::default(),
+    )
+    .unwrap());
+
+    assert_snapshot!(
+        "convert_type_only_imports_decorators",
+        tsimports_with_config(
+            "\
+import { Injectable } from '@nestjs/common'
+import { Repository } from './repository'
+import { Logger } from './logger'
+import { User } from './user'
+import { Options } from './options'
+
+@Injectable()
+export class UserService {
+  constructor(private readonly users: Repository<User>) {}
+
+  find(): User {
+    const logger: Logger = this.logger
+  }
+}
+
+class Plain {
+  constructor(options: Options) {}
+}
+",
+            JsFileSource::ts(),
+            &config,
+            &Resolver::default(),
+        )
+        .unwrap()
+    );
+}
+
+#[test]
+fn commonjs() {
+    assert_snapshot!(tsimports(
+        "\
+#!/usr/bin/env node
+'use strict'
+
+const { z } = require('zod')
+const path = require('node:path')
+// comment of foo
+const foo = require('./foo')
+const fs = require('fs').promises
+let a = 1, b = require('b')
+const chalk = require('chalk')
+
+require('./setup')
+
+const later = require('./later')
+const early = require('./early')
+
+main()
+",
+        JsFileSource::js_script(),
+    )
+    .unwrap());
+}
+
+#[test]
+fn import_equals() {
+    assert_snapshot!(tsimports(
+        "\
+import { join } from 'node:path'
+import foo = require('./foo');
+import type Schema = require(\"./schema\")
+import fs = require('fs')
+import log = console.log
+
+log(fs, foo)
+
+import axios from 'axios'
+",
+        JsFileSource::ts(),
+    )
+    .unwrap());
+}
+
+#[test]
+fn module_blocks() {
+    let input = "\
+import { b } from 'b'
+import { a } from 'a'
+
+declare module 'x' {
+  import { Foo } from './foo'
+  import type { Bar } from '@types/bar'
+
+  export function x(foo: Foo): Bar
+
+  export { y } from './y'
+}
+
+declare namespace Ns {
+  import log = console.log
+  import { Baz } from 'baz'
+}
+
+declare module 'm' {
+  /**
+   * doc of d
+   */
+  import d from 'd';
+  // comment of c
+  import c from 'c';
+}
+
+declare module 'empty' {}
+
+namespace Foo { export const x = 1 }
+";
+
+    let output = tsimports(input, JsFileSource::d_ts()).unwrap();
+    assert_eq!(
+        tsimports(output.as_str(), JsFileSource::d_ts()).unwrap(),
+        output
+    );
+    assert_snapshot!(output);
+}
+
+#[test]
+fn declaration_file() {
+    assert_snapshot!(tsimports(
+        "\
+/// <reference types=\"node\" />
+/// <reference path=\"./globals.d.ts\" />
+import { b } from 'b'
+import { a } from 'a'
+
+export {}
+
+declare global {
+  interface Window {
+    a: typeof a
+  }
+}
+
+import type { c } from 'c'
+",
+        JsFileSource::d_ts(),
+    )
+    .unwrap());
+}
+
+#[test]
+fn triple_slash_directives() {
+    assert_snapshot!(tsimports(
+        "\
+/// <reference types=\"vite/client\" />
+import { b } from 'b'
+import { a } from 'a'
+",
+        JsFileSource::ts(),
+    )
+    .unwrap());
+}